@@ -0,0 +1,52 @@
+// Compares the closed-form layer integration of the vertical sound path
+// with the time stepping integrator on a long deep profile
+// Run with: cargo bench --bench vertical_sound_path
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use ucnlphysx::*;
+
+const PHX_BENCH_DURATION: Duration = Duration::from_millis(500);
+
+// Calls f repeatedly for at least PHX_BENCH_DURATION, returns the result and the mean time per call
+fn bench<F: FnMut() -> f64>(mut f: F) -> (f64, Duration) {
+
+    let mut result = f();
+    let mut calls = 1u32;
+    let start = Instant::now();
+
+    while start.elapsed() < PHX_BENCH_DURATION {
+        result = black_box(f());
+        calls += 1;
+    }
+
+    (result, start.elapsed() / calls)
+}
+
+fn main() {
+
+    let g = PHX_GRAVITY_ACC_MPS2;
+
+    // thermocline over a deep isothermal layer, a point every 10 m down to 6000 m
+    let tuples: Vec<(f64, f64, f64)> = (0..=600).map(|i| {
+        let z = (i as f64) * 10.0;
+        (z, 2.0 + 18.0 * (-z / 300.0).exp(), 34.7 + 0.3 * (-z / 500.0).exp())
+    }).collect();
+    let ts_profile = TsProfile::from_tuples(&tuples).unwrap();
+
+    for &tof in [ 0.5, 2.0, 3.9 ].iter() {
+
+        println!("time of flight {} s", tof);
+
+        let (h_exact, t_exact) = bench(|| phx_try_vertical_sound_path_exact_ts_profile(black_box(tof), g, &ts_profile,
+                                                                                       EquationOfState::Eos80, SoundSpeedModel::ChenMillero).unwrap());
+        println!("  closed form:       {:>12.4} m {:>14?}", h_exact, t_exact);
+
+        for &n_t in [ 1000, 10000, 100000 ].iter() {
+            let (h, t) = bench(|| phx_try_vertical_sound_path_ts_profile(black_box(tof), n_t, g, &ts_profile,
+                                                                         EquationOfState::Eos80, SoundSpeedModel::ChenMillero).unwrap());
+            println!("  stepping n_t={:<6} {:>12.4} m {:>14?}, difference {:.4} m", n_t, h, t, h - h_exact);
+        }
+    }
+}
@@ -0,0 +1,437 @@
+// Alternative equations for the absorption of sound in seawater
+// All of them share the signature of alpha_e_francois_garrison_calc:
+// f - frequency, kHz
+// t - temperature, °C
+// s - salinity, PSU
+// h - depth, m
+// ph - acidity
+// and return absorption in dB/km
+
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use crate::{alpha_e_francois_garrison_calc, phx_check_step_count, phx_linterp, phx_ts_profile_layers_split, PhxError, TsProfile};
+
+/// Sound absorption equation
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum AbsorptionModel {
+    /// Francois and Garrison (1982), see alpha_e_francois_garrison_calc
+    #[default]
+    FrancoisGarrison,
+    /// Ainslie and McColm (1998), simplified Francois-Garrison
+    AinslieMcColm,
+    /// Fisher and Simmons (1977), for S = 35 PSU and pH = 8
+    FisherSimmons,
+    /// Thorp (1967), frequency only
+    Thorp,
+}
+
+impl AbsorptionModel {
+
+    /// Calculates absorption of sound in water, dB/km
+    /// f - frequency, kHz
+    /// t - temperature, °C
+    /// s - salinity, PSU
+    /// h - depth, m
+    /// ph - acidity
+    /// Parameters which are not used by the equation are ignored
+    pub fn alpha_calc(&self, f: f64, t: f64, s: f64, h: f64, ph: f64) -> f64 {
+        match *self {
+            AbsorptionModel::FrancoisGarrison => alpha_e_francois_garrison_calc(f, t, s, h, ph),
+            AbsorptionModel::AinslieMcColm => alpha_e_ainslie_mccolm_calc(f, t, s, h, ph),
+            AbsorptionModel::FisherSimmons => alpha_e_fisher_simmons_calc(f, t, s, h, ph),
+            AbsorptionModel::Thorp => alpha_e_thorp_calc(f, t, s, h, ph),
+        }
+    }
+}
+
+// Calculation of absorption according to:
+// Ainslie, M. A., McColm, J. G. (1998). A simplified formula for viscous and chemical absorption
+// in sea water. J. Acoust. Soc. Am., 103(3), 1671-1672
+pub fn alpha_e_ainslie_mccolm_calc(f: f64, t: f64, s: f64, h: f64, ph: f64) -> f64 {
+
+    let fsq = f * f;
+    let z = h / 1000.0;
+
+    // Boric acid contribution
+    let f1 = 0.78 * (s / 35.0).sqrt() * (t / 26.0).exp();
+    let boric = 0.106 * (f1 * fsq) / (f1 * f1 + fsq) * ((ph - 8.0) / 0.56).exp();
+
+    // MgSO4 contribution
+    let f2 = 42.0 * (t / 17.0).exp();
+    let mgso4 = 0.52 * (1.0 + t / 43.0) * (s / 35.0) * (f2 * fsq) / (f2 * f2 + fsq) * (-z / 6.0).exp();
+
+    // Pure water contribution
+    let h2o = 4.9E-4 * fsq * (-(t / 27.0 + z / 17.0)).exp();
+
+    boric + mgso4 + h2o
+}
+
+// Calculation of absorption according to:
+// Fisher, F. H., Simmons, V. P. (1977). Sound absorption in sea water.
+// J. Acoust. Soc. Am., 62(3), 558-564
+// The equation is given for S = 35 PSU and pH = 8, salinity and acidity are ignored
+pub fn alpha_e_fisher_simmons_calc(f: f64, t: f64, _s: f64, h: f64, _ph: f64) -> f64 {
+
+    let f = f * 1000.0;
+    let fsq = f * f;
+    let t_kel = 273.1 + t;
+    // gauge pressure, atm
+    let p = h / 10.0;
+
+    // Boric acid contribution
+    let a1 = (-5.22E-12 * t + 2.36E-10) * t + 1.03E-8;
+    let f1 = 1.32E3 * t_kel * (-1700.0 / t_kel).exp();
+    let boric = a1 * (f1 * fsq) / (f1 * f1 + fsq);
+
+    // MgSO4 contribution
+    let a2 = 7.52E-10 * t + 5.62E-8;
+    let p2 = (3.7E-7 * p - 10.3E-4) * p + 1.0;
+    let f2 = 1.55E7 * t_kel * (-3052.0 / t_kel).exp();
+    let mgso4 = a2 * p2 * (f2 * fsq) / (f2 * f2 + fsq);
+
+    // Pure water contribution
+    let a3 = (((-3.48E-4 * t + 4.77E-2) * t - 2.37) * t + 55.9) * 1E-15;
+    let p3 = (7.57E-8 * p - 3.84E-4) * p + 1.0;
+    let h2o = a3 * p3 * fsq;
+
+    // Np/m to dB/km
+    (boric + mgso4 + h2o) * 20.0 / 10f64.ln() * 1000.0
+}
+
+// Calculation of absorption according to:
+// Thorp, W. H. (1967). Analytic description of the low-frequency attenuation coefficient.
+// J. Acoust. Soc. Am., 42(1), 270
+// in the form given by Jensen, F. B. et al. Computational Ocean Acoustics, 2011, eq. 1.47
+// Only frequency is taken into account
+pub fn alpha_e_thorp_calc(f: f64, _t: f64, _s: f64, _h: f64, _ph: f64) -> f64 {
+
+    let fsq = f * f;
+    3.3E-3 + 0.11 * fsq / (1.0 + fsq) + 44.0 * fsq / (4100.0 + fsq) + 3.0E-4 * fsq
+}
+
+/// Absorption loss within a layer between two adjacent points of a TS profile
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AbsorptionLayer {
+    /// depth of the upper boundary of the traversed part of the layer, m
+    pub z_top: f64,
+    /// depth of the lower boundary of the traversed part of the layer, m
+    pub z_bottom: f64,
+    /// length of the acoustic path within the layer, m
+    pub path: f64,
+    /// absorption loss within the layer, dB
+    pub loss: f64,
+}
+
+/// Absorption loss along an acoustic path
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathAbsorption {
+    /// total absorption loss, dB
+    pub loss: f64,
+    /// total length of the acoustic path, m
+    pub path: f64,
+    /// per-layer breakdown, ordered by depth
+    pub layers: Vec<AbsorptionLayer>,
+}
+
+// Calculates absorption loss along a straight acoustic path between two depths
+// considering the given temperature and salinity profile
+// f - frequency, kHz
+// ph - acidity
+// z1, z2 - depths of the path ends, m (in any order)
+// angle_rad - angle between the path and the vertical, rad (0 - vertical path)
+// n_z - number of depth intervals for integration
+// ts_profile - vertical Temperature-Salinity profile
+// model - absorption equation
+#[allow(clippy::too_many_arguments)]
+pub fn phx_try_path_absorption_ts_profile(f: f64, ph: f64, z1: f64, z2: f64, angle_rad: f64, n_z: i32,
+                                          ts_profile: &TsProfile, model: AbsorptionModel) -> Result<PathAbsorption, PhxError> {
+
+    phx_check_step_count(n_z)?;
+
+    if !(0.0..FRAC_PI_2).contains(&angle_rad) {
+        return Err(PhxError::AngleOutOfRange { value: angle_rad, min: 0.0, max: FRAC_PI_2 });
+    }
+
+    let z_max = ts_profile.max_depth();
+    for &z in [ z1, z2 ].iter() {
+        if !(z >= 0.0 && z <= z_max) {
+            return Err(PhxError::DepthOutOfRange { value: z, min: 0.0, max: z_max });
+        }
+    }
+
+    let sec = 1.0 / angle_rad.cos();
+
+    let mut result = PathAbsorption { loss: 0.0, path: 0.0, layers: Vec::new() };
+
+    // the same layers and intervals as of phx_try_harmonic_mean_sound_speed_ts_profile
+    for (z_top, z_bottom, n) in phx_ts_profile_layers_split(ts_profile, z1, z2, n_z) {
+
+        // midpoint integration over the traversed part of the layer
+        let dz = (z_bottom - z_top) / (n as f64);
+        let mut alpha = 0.0;
+
+        for k in 0..n {
+            let p = ts_profile.interpolate(z_top + (k as f64 + 0.5) * dz);
+            alpha += model.alpha_calc(f, p.t, p.s, p.z, ph);
+        }
+
+        let path = (z_bottom - z_top) * sec;
+        // dB/km to dB
+        let loss = alpha * dz * sec / 1000.0;
+
+        result.loss += loss;
+        result.path += path;
+        result.layers.push(AbsorptionLayer { z_top, z_bottom, path, loss });
+    }
+
+    Ok(result)
+}
+
+/// Spectral weighting of a signal within a frequency band
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum SpectralWeighting {
+    /// equal power over the band
+    #[default]
+    Flat,
+    /// Hann window over the band
+    Hann,
+    /// power spectral density samples equally spaced from the lower to the upper
+    /// frequency of the band, linearly interpolated in between
+    Samples(Vec<f64>),
+}
+
+impl SpectralWeighting {
+
+    fn check(&self) -> Result<(), PhxError> {
+        match self {
+            SpectralWeighting::Samples(w) => {
+                if w.len() < 2 || w.iter().any(|&x| !(x.is_finite() && x >= 0.0)) || w.iter().sum::<f64>() <= 0.0 {
+                    Err(PhxError::InvalidSpectralWeighting)
+                } else {
+                    Ok(())
+                }
+            },
+            _ => Ok(()),
+        }
+    }
+
+    // Weight at the relative position x (0..1) within the band
+    fn weight(&self, x: f64) -> f64 {
+        match self {
+            SpectralWeighting::Flat => 1.0,
+            SpectralWeighting::Hann => 0.5 * (1.0 - (2.0 * PI * x).cos()),
+            SpectralWeighting::Samples(w) => {
+                let pos = x * ((w.len() - 1) as f64);
+                let idx = (pos.floor() as usize).min(w.len() - 2);
+                phx_linterp(idx as f64, w[idx], (idx + 1) as f64, w[idx + 1], pos)
+            },
+        }
+    }
+}
+
+const PHX_BAND_GRID_SIZE: usize = 257; // number of frequencies for the band integration
+
+/// Absorption of sound over a frequency band
+#[derive(Debug, Clone, PartialEq)]
+pub struct BandAbsorption {
+    /// weighted mean absorption over the band, dB/km
+    pub alpha_mean: f64,
+    /// minimal absorption within the band, dB/km
+    pub alpha_min: f64,
+    /// maximal absorption within the band, dB/km
+    pub alpha_max: f64,
+    /// spectral tilt, the weighted least squares slope of absorption by frequency, dB/(km*kHz)
+    pub tilt: f64,
+    // (relative weight, absorption in dB/km) on the integration grid
+    spectrum: Vec<(f64, f64)>,
+}
+
+impl BandAbsorption {
+
+    /// In-band absorption spread, dB/km
+    pub fn spread(&self) -> f64 {
+        self.alpha_max - self.alpha_min
+    }
+
+    /// Effective absorption loss of the band signal power over the given range, dB
+    /// range - distance, m
+    pub fn effective_loss(&self, range: f64) -> f64 {
+        let r_km = range / 1000.0;
+        let p = self.spectrum.iter().map(|&(w, a)| w * 10f64.powf(-a * r_km / 10.0)).sum::<f64>();
+        -10.0 * p.log10()
+    }
+}
+
+// Calculates absorption of a broadband signal over a frequency band
+// f_low, f_high - lower and upper frequencies of the band, kHz
+// t - temperature, °C
+// s - salinity, PSU
+// h - depth, m
+// ph - acidity
+// weighting - power spectral density of the signal within the band
+// model - absorption equation
+#[allow(clippy::too_many_arguments)]
+pub fn phx_try_band_absorption_calc(f_low: f64, f_high: f64, t: f64, s: f64, h: f64, ph: f64,
+                                    weighting: &SpectralWeighting, model: AbsorptionModel) -> Result<BandAbsorption, PhxError> {
+
+    if !(f_low > 0.0 && f_high > f_low && f_high.is_finite()) {
+        return Err(PhxError::InvalidBand { f_low, f_high });
+    }
+
+    weighting.check()?;
+
+    let n = PHX_BAND_GRID_SIZE;
+    let df = (f_high - f_low) / ((n - 1) as f64);
+
+    // trapezoidal weights
+    let mut spectrum: Vec<(f64, f64)> = (0..n).map(|i| {
+        let x = (i as f64) / ((n - 1) as f64);
+        let trap = if i == 0 || i == n - 1 { 0.5 } else { 1.0 };
+        (trap * weighting.weight(x), model.alpha_calc(f_low + (i as f64) * df, t, s, h, ph))
+    }).collect();
+
+    let w_sum: f64 = spectrum.iter().map(|&(w, _)| w).sum();
+    for point in spectrum.iter_mut() {
+        point.0 /= w_sum;
+    }
+
+    let alpha_mean = spectrum.iter().map(|&(w, a)| w * a).sum::<f64>();
+    let alpha_min = spectrum.iter().map(|&(_, a)| a).fold(f64::INFINITY, f64::min);
+    let alpha_max = spectrum.iter().map(|&(_, a)| a).fold(f64::NEG_INFINITY, f64::max);
+
+    let f_mean = spectrum.iter().enumerate().map(|(i, &(w, _))| w * (f_low + (i as f64) * df)).sum::<f64>();
+    let mut cov = 0.0;
+    let mut var = 0.0;
+    for (i, &(w, a)) in spectrum.iter().enumerate() {
+        let d = f_low + (i as f64) * df - f_mean;
+        cov += w * d * (a - alpha_mean);
+        var += w * d * d;
+    }
+
+    Ok(BandAbsorption { alpha_mean, alpha_min, alpha_max, tilt: cov / var, spectrum })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    #[test]
+    fn alpha_e_thorp_calc_test() {
+
+        // Jensen et al. (2011), eq. 1.47: about 1.19 dB/km at 10 kHz
+        assert_approx_eq!(alpha_e_thorp_calc(10.0, 0.0, 0.0, 0.0, 0.0), 1.19, 0.005);
+        assert_approx_eq!(alpha_e_thorp_calc(1.0, 0.0, 0.0, 0.0, 0.0), 0.06933, 1E-5);
+        // about 34.3 dB/km at 100 kHz, where the magnesium sulphate and pure water terms dominate
+        assert_approx_eq!(alpha_e_thorp_calc(100.0, 0.0, 0.0, 0.0, 0.0), 34.32, 0.01);
+        assert_approx_eq!(AbsorptionModel::Thorp.alpha_calc(10.0, 25.0, 0.0, 5000.0, 7.0), 1.18983, 1E-5);
+    }
+
+    #[test]
+    fn absorption_models_test() {
+
+        assert_eq!(AbsorptionModel::default(), AbsorptionModel::FrancoisGarrison);
+        assert_approx_eq!(AbsorptionModel::FrancoisGarrison.alpha_calc(12.0, 10.0, 35.0, 100.0, 8.0),
+                          alpha_e_francois_garrison_calc(12.0, 10.0, 35.0, 100.0, 8.0), 1E-12);
+
+        let models = [ AbsorptionModel::AinslieMcColm, AbsorptionModel::FisherSimmons, AbsorptionModel::Thorp ];
+        let ref_f: [f64; 5] = [ 2.0, 5.0, 10.0, 20.0, 40.0 ];
+
+        // at 4 °C, 35 PSU, pH 8 Ainslie-McColm follows Francois-Garrison within 5%,
+        // older equations deviate up to 30%
+        for &f in ref_f.iter() {
+            let alpha_fg = alpha_e_francois_garrison_calc(f, 4.0, 35.0, 1000.0, 8.0);
+            for model in models.iter() {
+                let alpha = model.alpha_calc(f, 4.0, 35.0, 1000.0, 8.0);
+                let tolerance = if *model == AbsorptionModel::AinslieMcColm { 0.05 } else { 0.3 };
+                assert!((alpha / alpha_fg - 1.0).abs() < tolerance, "{:?}, f = {} kHz: {} vs {}", model, f, alpha, alpha_fg);
+            }
+        }
+
+        // absorption decreases with depth
+        for model in [ AbsorptionModel::FrancoisGarrison, AbsorptionModel::AinslieMcColm, AbsorptionModel::FisherSimmons ].iter() {
+            assert!(model.alpha_calc(30.0, 4.0, 35.0, 4000.0, 8.0) < model.alpha_calc(30.0, 4.0, 35.0, 0.0, 8.0));
+        }
+    }
+
+    #[test]
+    fn phx_try_path_absorption_ts_profile_test() {
+
+        let profile = TsProfile::from_tuples(&[ (0.0, 20.0, 35.0), (100.0, 10.0, 35.0), (1000.0, 4.0, 34.8), (3000.0, 2.0, 34.7) ]).unwrap();
+        let model = AbsorptionModel::FrancoisGarrison;
+
+        // homogeneous water: loss = alpha * path
+        let uniform = TsProfile::from_tuples(&[ (0.0, 10.0, 35.0), (2000.0, 10.0, 35.0) ]).unwrap();
+        let r = phx_try_path_absorption_ts_profile(10.0, 8.0, 0.0, 2000.0, 0.0, 1000, &uniform, AbsorptionModel::Thorp).unwrap();
+        assert_approx_eq!(r.loss, 2.0 * alpha_e_thorp_calc(10.0, 0.0, 0.0, 0.0, 0.0), 1E-9);
+        assert_approx_eq!(r.path, 2000.0, 1E-9);
+
+        let r = phx_try_path_absorption_ts_profile(10.0, 8.0, 0.0, 3000.0, 0.0, 3000, &profile, model).unwrap();
+        assert_eq!(r.layers.len(), 3);
+        assert_approx_eq!(r.layers.iter().map(|l| l.loss).sum::<f64>(), r.loss, 1E-9);
+        assert_approx_eq!(r.path, 3000.0, 1E-9);
+
+        // convergence
+        let coarse = phx_try_path_absorption_ts_profile(10.0, 8.0, 0.0, 3000.0, 0.0, 30, &profile, model).unwrap();
+        assert_approx_eq!(coarse.loss, r.loss, 1E-3);
+
+        // partial path in any order, slant path
+        let part = phx_try_path_absorption_ts_profile(10.0, 8.0, 2000.0, 50.0, 0.0, 1000, &profile, model).unwrap();
+        assert_approx_eq!(part.layers[0].z_top, 50.0, 1E-12);
+        assert_approx_eq!(part.layers[2].z_bottom, 2000.0, 1E-12);
+
+        // the layers are those of the sound speed integration along the same path
+        let split = phx_ts_profile_layers_split(&profile, 2000.0, 50.0, 1000);
+        assert_eq!(part.layers.iter().map(|l| (l.z_top, l.z_bottom)).collect::<Vec<_>>(),
+                   split.iter().map(|&(z_top, z_bottom, _)| (z_top, z_bottom)).collect::<Vec<_>>());
+
+        let angle = 60.0f64.to_radians();
+        let slant = phx_try_path_absorption_ts_profile(10.0, 8.0, 2000.0, 50.0, angle, 1000, &profile, model).unwrap();
+        assert_approx_eq!(slant.loss, 2.0 * part.loss, 1E-9);
+        assert_approx_eq!(slant.path, 2.0 * 1950.0, 1E-9);
+
+        assert_eq!(phx_try_path_absorption_ts_profile(10.0, 8.0, 0.0, 3500.0, 0.0, 100, &profile, model),
+                   Err(PhxError::DepthOutOfRange { value: 3500.0, min: 0.0, max: 3000.0 }));
+        assert_eq!(phx_try_path_absorption_ts_profile(10.0, 8.0, 0.0, 3000.0, 0.0, 0, &profile, model),
+                   Err(PhxError::InvalidStepCount { steps: 0 }));
+        assert!(phx_try_path_absorption_ts_profile(10.0, 8.0, 0.0, 3000.0, FRAC_PI_2, 100, &profile, model).is_err());
+    }
+
+    #[test]
+    fn phx_try_band_absorption_calc_test() {
+
+        let model = AbsorptionModel::FrancoisGarrison;
+
+        // absorption grows with frequency within the band almost linearly
+        let band = phx_try_band_absorption_calc(20.0, 30.0, 10.0, 35.0, 100.0, 8.0, &SpectralWeighting::Flat, model).unwrap();
+        assert_approx_eq!(band.alpha_min, model.alpha_calc(20.0, 10.0, 35.0, 100.0, 8.0), 1E-12);
+        assert_approx_eq!(band.alpha_max, model.alpha_calc(30.0, 10.0, 35.0, 100.0, 8.0), 1E-12);
+        assert!(band.alpha_mean > band.alpha_min && band.alpha_mean < band.alpha_max);
+        assert!(band.tilt > 0.0);
+        assert_approx_eq!(band.tilt * 10.0, band.spread(), 0.1 * band.spread());
+
+        // a symmetric weighting keeps the mean of an almost linear dependence
+        let hann = phx_try_band_absorption_calc(20.0, 30.0, 10.0, 35.0, 100.0, 8.0, &SpectralWeighting::Hann, model).unwrap();
+        assert_approx_eq!(hann.alpha_mean, band.alpha_mean, 0.05 * band.spread());
+        assert_approx_eq!(hann.alpha_min, band.alpha_min, 1E-12);
+
+        // flat samples are the same as Flat, tilting the spectrum up raises the mean absorption
+        let samples = phx_try_band_absorption_calc(20.0, 30.0, 10.0, 35.0, 100.0, 8.0, &SpectralWeighting::Samples(vec![ 2.0, 2.0, 2.0 ]), model).unwrap();
+        assert_approx_eq!(samples.alpha_mean, band.alpha_mean, 1E-12);
+        let rising = phx_try_band_absorption_calc(20.0, 30.0, 10.0, 35.0, 100.0, 8.0, &SpectralWeighting::Samples(vec![ 0.0, 1.0 ]), model).unwrap();
+        assert!(rising.alpha_mean > band.alpha_mean);
+
+        // effective loss: zero range, then between the extremes and less than the mean
+        assert_approx_eq!(band.effective_loss(0.0), 0.0, 1E-12);
+        let loss = band.effective_loss(5000.0);
+        assert!(loss > band.alpha_min * 5.0 && loss < band.alpha_mean * 5.0);
+
+        assert_eq!(phx_try_band_absorption_calc(30.0, 20.0, 10.0, 35.0, 100.0, 8.0, &SpectralWeighting::Flat, model),
+                   Err(PhxError::InvalidBand { f_low: 30.0, f_high: 20.0 }));
+        assert_eq!(phx_try_band_absorption_calc(20.0, 30.0, 10.0, 35.0, 100.0, 8.0, &SpectralWeighting::Samples(vec![ 1.0 ]), model),
+                   Err(PhxError::InvalidSpectralWeighting));
+        assert_eq!(phx_try_band_absorption_calc(20.0, 30.0, 10.0, 35.0, 100.0, 8.0, &SpectralWeighting::Samples(vec![ 0.0, -1.0 ]), model),
+                   Err(PhxError::InvalidSpectralWeighting));
+    }
+}
@@ -0,0 +1,359 @@
+// Site parameters configured once and used throughout a mission
+
+use std::sync::OnceLock;
+
+use crate::gravity::phx_gravity_somigliana_calc;
+use crate::{AbsorptionModel, EquationOfState, GravityModel, PhxError, PressureDepthTable, SoundSpeedModel, TsPoint, TsProfile};
+use crate::{PHX_ATM_PRESSURE_MBAR, PHX_FWTR_SALINITY_PSU};
+
+const PHX_ENVIRONMENT_MAX_DEPTH: f64 = 11000.0;  // m, depth of the homogeneous water column without a TS profile
+const PHX_ENVIRONMENT_STEPS_PER_M: f64 = 1.0;    // depth intervals per meter of the pressure-depth table
+
+// Pressure-depth table built on the first use, it is derived from the rest of the fields
+// and does not take part in the comparison
+#[derive(Debug, Clone, Default)]
+struct PressureDepthCache(OnceLock<PressureDepthTable>);
+
+impl PartialEq for PressureDepthCache {
+    fn eq(&self, _other: &PressureDepthCache) -> bool {
+        true
+    }
+}
+
+/// Parameters of a site: location, surface conditions, water properties and the chosen property models
+#[derive(Debug, Clone, PartialEq)]
+pub struct Environment {
+    // latitude, rad
+    lat_rad: f64,
+    // ellipsoidal height of the water surface, m (e.g. of a mountain lake)
+    altitude: f64,
+    // atmospheric pressure at the water surface, mBar
+    p0: f64,
+    // acidity
+    ph: f64,
+    // temperature of the water, °C, used when there is no TS profile
+    temperature: f64,
+    // salinity of the water, PSU, used when there is no TS profile
+    salinity: f64,
+    // vertical Temperature-Salinity profile
+    ts_profile: Option<TsProfile>,
+    // equation of state to calculate water density
+    eos: EquationOfState,
+    // sound speed equation
+    sound_speed_model: SoundSpeedModel,
+    // sound absorption equation
+    absorption_model: AbsorptionModel,
+    table: PressureDepthCache,
+}
+
+impl Environment {
+
+    /// Creates an environment at the given latitude (rad) at sea level with standard atmospheric pressure,
+    /// the rest of the parameters are for homogeneous water at 10 °C, 35 PSU, pH 8 and the default models
+    pub fn new(lat_rad: f64) -> Environment {
+        Environment {
+            lat_rad,
+            altitude: 0.0,
+            p0: PHX_ATM_PRESSURE_MBAR,
+            ph: 8.0,
+            temperature: 10.0,
+            salinity: 35.0,
+            ts_profile: None,
+            eos: EquationOfState::default(),
+            sound_speed_model: SoundSpeedModel::default(),
+            absorption_model: AbsorptionModel::default(),
+            table: PressureDepthCache::default(),
+        }
+    }
+
+    /// Creates an environment with the given TS profile, see Environment::new
+    pub fn with_ts_profile(lat_rad: f64, ts_profile: TsProfile) -> Environment {
+        let mut env = Environment::new(lat_rad);
+        env.set_ts_profile(Some(ts_profile));
+        env
+    }
+
+    /// Creates a fresh water environment, see Environment::new
+    pub fn fresh_water(lat_rad: f64, altitude: f64, temperature: f64) -> Environment {
+        let mut env = Environment::new(lat_rad);
+        env.set_altitude(altitude);
+        env.set_water(temperature, PHX_FWTR_SALINITY_PSU);
+        env
+    }
+
+    /// Latitude, rad
+    pub fn lat_rad(&self) -> f64 {
+        self.lat_rad
+    }
+
+    /// Ellipsoidal height of the water surface, m
+    pub fn altitude(&self) -> f64 {
+        self.altitude
+    }
+
+    /// Atmospheric pressure at the water surface, mBar
+    pub fn p0(&self) -> f64 {
+        self.p0
+    }
+
+    /// Acidity
+    pub fn ph(&self) -> f64 {
+        self.ph
+    }
+
+    /// Temperature of the water, °C, used when there is no TS profile
+    pub fn temperature(&self) -> f64 {
+        self.temperature
+    }
+
+    /// Salinity of the water, PSU, used when there is no TS profile
+    pub fn salinity(&self) -> f64 {
+        self.salinity
+    }
+
+    /// Vertical Temperature-Salinity profile, if any
+    pub fn ts_profile(&self) -> Option<&TsProfile> {
+        self.ts_profile.as_ref()
+    }
+
+    /// Equation of state to calculate water density
+    pub fn eos(&self) -> EquationOfState {
+        self.eos
+    }
+
+    /// Sound speed equation
+    pub fn sound_speed_model(&self) -> SoundSpeedModel {
+        self.sound_speed_model
+    }
+
+    /// Sound absorption equation
+    pub fn absorption_model(&self) -> AbsorptionModel {
+        self.absorption_model
+    }
+
+    /// Sets the latitude, rad
+    pub fn set_lat_rad(&mut self, lat_rad: f64) {
+        self.lat_rad = lat_rad;
+        self.table = PressureDepthCache::default();
+    }
+
+    /// Sets the ellipsoidal height of the water surface, m
+    pub fn set_altitude(&mut self, altitude: f64) {
+        self.altitude = altitude;
+        self.table = PressureDepthCache::default();
+    }
+
+    /// Sets the atmospheric pressure at the water surface, mBar
+    pub fn set_p0(&mut self, p0: f64) {
+        self.p0 = p0;
+        self.table = PressureDepthCache::default();
+    }
+
+    /// Sets the acidity
+    pub fn set_ph(&mut self, ph: f64) {
+        self.ph = ph;
+    }
+
+    /// Sets the temperature (°C) and salinity (PSU) of the water, used when there is no TS profile
+    pub fn set_water(&mut self, temperature: f64, salinity: f64) {
+        self.temperature = temperature;
+        self.salinity = salinity;
+        self.table = PressureDepthCache::default();
+    }
+
+    /// Sets the vertical Temperature-Salinity profile, None for homogeneous water
+    pub fn set_ts_profile(&mut self, ts_profile: Option<TsProfile>) {
+        self.ts_profile = ts_profile;
+        self.table = PressureDepthCache::default();
+    }
+
+    /// Sets the equation of state to calculate water density
+    pub fn set_eos(&mut self, eos: EquationOfState) {
+        self.eos = eos;
+        self.table = PressureDepthCache::default();
+    }
+
+    /// Sets the sound speed equation
+    pub fn set_sound_speed_model(&mut self, sound_speed_model: SoundSpeedModel) {
+        self.sound_speed_model = sound_speed_model;
+    }
+
+    /// Sets the sound absorption equation
+    pub fn set_absorption_model(&mut self, absorption_model: AbsorptionModel) {
+        self.absorption_model = absorption_model;
+    }
+
+    /// Gravity acceleration at the water surface, m/s^2
+    /// At sea level it is phx_gravity_constant_wgs84_calc(lat_rad), above the ellipsoid
+    /// (e.g. a mountain lake) the Somigliana normal gravity with the height correction is used,
+    /// which is about 3.086E-6 m/s^2 less per meter of altitude
+    pub fn g(&self) -> f64 {
+        phx_gravity_somigliana_calc(self.lat_rad, self.altitude)
+    }
+
+    /// Gravity model of the water column
+    pub fn gravity_model(&self) -> GravityModel {
+        GravityModel::Somigliana { lat_rad: self.lat_rad, height: self.altitude }
+    }
+
+    /// Deepest point covered by the TS profile, m (PHX_ENVIRONMENT_MAX_DEPTH without a profile)
+    pub fn max_depth(&self) -> f64 {
+        self.ts_profile.as_ref().map_or(PHX_ENVIRONMENT_MAX_DEPTH, |ts_profile| ts_profile.max_depth())
+    }
+
+    /// Temperature and salinity at the given depth
+    /// z - depth, m
+    pub fn water_at(&self, z: f64) -> Result<TsPoint, PhxError> {
+
+        self.check_depth(z)?;
+
+        Ok(match &self.ts_profile {
+            Some(ts_profile) => ts_profile.interpolate(z),
+            None => TsPoint { z, t: self.temperature, s: self.salinity },
+        })
+    }
+
+    /// Depth by the measured pressure, m
+    /// p - pressure, mBar
+    pub fn depth_from_pressure(&self, p: f64) -> Result<f64, PhxError> {
+        self.pressure_depth_table()?.try_depth_calc(p)
+    }
+
+    /// Pressure at the given depth, mBar
+    /// z - depth, m
+    pub fn pressure_at(&self, z: f64) -> Result<f64, PhxError> {
+        self.pressure_depth_table()?.try_pressure_calc(z)
+    }
+
+    /// Speed of sound at the given depth, m/s
+    /// The pressure is the absolute pressure of the pressure-depth table (in situ density),
+    /// the TS-profile integrators use the density at the surface instead, so their sound speed
+    /// is slightly lower at depth (by about 0.04 m/s at 1000 m)
+    /// z - depth, m
+    pub fn sound_speed_at(&self, z: f64) -> Result<f64, PhxError> {
+
+        let water = self.water_at(z)?;
+        let p = self.pressure_at(z)?;
+        Ok(self.sound_speed_model.speed_calc(water.t, p, water.s, z))
+    }
+
+    /// Absorption of sound at the given depth, dB/km
+    /// f - frequency, kHz
+    /// z - depth, m
+    pub fn absorption_at(&self, f: f64, z: f64) -> Result<f64, PhxError> {
+
+        let water = self.water_at(z)?;
+        Ok(self.absorption_model.alpha_calc(f, water.t, water.s, z, self.ph))
+    }
+
+    // Pressure-depth table of the water column, which is homogeneous without a TS profile
+    fn pressure_depth_table(&self) -> Result<&PressureDepthTable, PhxError> {
+
+        if let Some(table) = self.table.0.get() {
+            return Ok(table);
+        }
+
+        let homogeneous;
+        let ts_profile = match &self.ts_profile {
+            Some(ts_profile) => ts_profile,
+            None => {
+                homogeneous = TsProfile::from_tuples(&[ (0.0, self.temperature, self.salinity),
+                                                        (PHX_ENVIRONMENT_MAX_DEPTH, self.temperature, self.salinity) ])?;
+                &homogeneous
+            },
+        };
+
+        let n_z = ((ts_profile.max_depth() * PHX_ENVIRONMENT_STEPS_PER_M).ceil() as i32).max(1);
+        let table = PressureDepthTable::try_new_gravity_model(self.p0, self.gravity_model(), ts_profile, n_z, self.eos)?;
+        Ok(self.table.0.get_or_init(|| table))
+    }
+
+    fn check_depth(&self, z: f64) -> Result<(), PhxError> {
+
+        let z_max = self.max_depth();
+        if !(z >= 0.0 && z <= z_max) {
+            return Err(PhxError::DepthOutOfRange { value: z, min: 0.0, max: z_max });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{assert_approx_eq, phx_gravity_constant_wgs84_calc, phx_speed_of_sound_unesco_calc};
+    use crate::{phx_try_depth_by_pressure_adaptive_ts_profile_gravity_model, DepthTolerance};
+    use crate::travel_time::phx_ts_profile_speed;
+
+    #[test]
+    fn environment_homogeneous_test() {
+
+        let lat_rad = 48.5f64.to_radians();
+        let env = Environment::new(lat_rad);
+        assert_approx_eq!(env.g(), phx_gravity_constant_wgs84_calc(lat_rad), 1E-12);
+
+        let p = env.pressure_at(100.0).unwrap();
+        assert_approx_eq!(env.depth_from_pressure(p).unwrap(), 100.0, 1E-9);
+        assert_approx_eq!(env.depth_from_pressure(env.p0()).unwrap(), 0.0, 1E-12);
+        assert!(env.depth_from_pressure(env.p0() - 1.0).is_err());
+        assert_eq!(env.pressure_at(12000.0), Err(PhxError::DepthOutOfRange { value: 12000.0, min: 0.0, max: PHX_ENVIRONMENT_MAX_DEPTH }));
+
+        // the same hydrostatic model as with a TS profile of the same water
+        let column = TsProfile::from_tuples(&[ (0.0, 10.0, 35.0), (PHX_ENVIRONMENT_MAX_DEPTH, 10.0, 35.0) ]).unwrap();
+        let h = phx_try_depth_by_pressure_adaptive_ts_profile_gravity_model(p, env.p0(), env.gravity_model(), &column,
+                                                                            DepthTolerance::Absolute(1E-6), env.eos()).unwrap();
+        assert_approx_eq!(h.depth, 100.0, 1E-3);
+        assert_eq!(Environment::with_ts_profile(lat_rad, column.clone()).pressure_at(100.0), Ok(p));
+
+        // sound speed at the absolute pressure of the table
+        assert_approx_eq!(env.sound_speed_at(0.0).unwrap(), phx_speed_of_sound_unesco_calc(10.0, PHX_ATM_PRESSURE_MBAR, 35.0), 1E-9);
+        assert_approx_eq!(env.sound_speed_at(1000.0).unwrap(), phx_speed_of_sound_unesco_calc(10.0, env.pressure_at(1000.0).unwrap(), 35.0), 1E-9);
+
+        // the TS-profile integrators take the pressure with the surface density
+        let c_integrators = phx_ts_profile_speed(&column, 1000.0, env.g(), env.eos(), env.sound_speed_model());
+        assert_approx_eq!(env.sound_speed_at(1000.0).unwrap() - c_integrators, 0.04, 0.01);
+        assert_approx_eq!(env.absorption_at(12.0, 50.0).unwrap(), AbsorptionModel::FrancoisGarrison.alpha_calc(12.0, 10.0, 35.0, 50.0, 8.0), 1E-12);
+
+        // a mountain lake: weaker gravity, fresh water
+        let lake = Environment::fresh_water(lat_rad, 3812.0, 10.0);
+        assert!(lake.g() < env.g());
+        assert!(lake.pressure_at(100.0).unwrap() < p);
+        assert!(lake.sound_speed_at(10.0).unwrap() < env.sound_speed_at(10.0).unwrap());
+
+        // the free-air correction of the sea level gravity
+        assert_approx_eq!(lake.g(), phx_gravity_constant_wgs84_calc(lat_rad) - 3.086E-6 * 3812.0, 3E-5);
+    }
+
+    #[test]
+    fn environment_ts_profile_test() {
+
+        let ts_profile = TsProfile::from_tuples(&[ (0.0, 20.0, 35.0), (100.0, 12.0, 35.0), (1000.0, 4.0, 34.8) ]).unwrap();
+        let mut env = Environment::with_ts_profile(0.5, ts_profile);
+        env.set_sound_speed_model(SoundSpeedModel::DelGrosso);
+
+        // pressure and depth are consistent
+        for &z in [ 0.0, 50.0, 100.0, 640.0 ].iter() {
+            let p = env.pressure_at(z).unwrap();
+            assert_approx_eq!(env.depth_from_pressure(p).unwrap(), z, 1E-6);
+        }
+
+        // water properties come from the profile
+        let water = env.water_at(550.0).unwrap();
+        assert_approx_eq!(water.t, 8.0, 1E-9);
+        assert_approx_eq!(water.s, 34.9, 1E-9);
+        assert!(env.sound_speed_at(0.0).unwrap() > env.sound_speed_at(1000.0).unwrap());
+        assert_approx_eq!(env.absorption_at(12.0, 1000.0).unwrap(), AbsorptionModel::FrancoisGarrison.alpha_calc(12.0, 4.0, 34.8, 1000.0, 8.0), 1E-12);
+
+        assert_eq!(env.sound_speed_at(1500.0), Err(PhxError::DepthOutOfRange { value: 1500.0, min: 0.0, max: 1000.0 }));
+        assert_eq!(env.water_at(-1.0), Err(PhxError::DepthOutOfRange { value: -1.0, min: 0.0, max: 1000.0 }));
+
+        // the table is rebuilt after a change of the surface pressure
+        let p = env.pressure_at(500.0).unwrap();
+        env.set_p0(env.p0() + 10.0);
+        assert_approx_eq!(env.pressure_at(500.0).unwrap(), p + 10.0, 0.05);
+        env.set_ts_profile(None);
+        assert_approx_eq!(env.max_depth(), PHX_ENVIRONMENT_MAX_DEPTH, 1E-12);
+    }
+}
@@ -0,0 +1,73 @@
+use std::error::Error;
+use std::fmt;
+
+/// Errors reported by the fallible (phx_try_*) calculations
+#[derive(Debug, Clone, PartialEq)]
+pub enum PhxError {
+    /// TS-profile contains less than two points
+    TooFewPoints { count: usize },
+    /// Specified number of integration intervals is not greater than zero
+    InvalidStepCount { steps: i32 },
+    /// Specified pressure (mBar) is beyond the pressure range covered by the TS-profile or by a correlation
+    PressureOutOfRange { value: f64, min: f64, max: f64 },
+    /// Specified time of flight (sec) is beyond the range covered by the TS-profile
+    TimeOfFlightOutOfRange { value: f64, min: f64, max: f64 },
+    /// Depth of the TS-profile point with the given index is less than the depth of the previous one
+    NonMonotonicDepth { index: usize },
+    /// TS-profile point with the given index contains a NaN or infinite value
+    NonFiniteValue { index: usize },
+    /// The shallowest point of the profile (z, m) is above the water surface,
+    /// or not at the surface for a sound speed profile
+    NotAtSurface { z: f64 },
+    /// Specified depth (m) is beyond the depth range covered by the TS-profile
+    DepthOutOfRange { value: f64, min: f64, max: f64 },
+    /// Specified angle (rad) is beyond the allowed range
+    AngleOutOfRange { value: f64, min: f64, max: f64 },
+    /// Frequency band (kHz) is empty or has non-positive boundaries
+    InvalidBand { f_low: f64, f_high: f64 },
+    /// Spectral weighting samples are fewer than two, negative, non-finite or all zero
+    InvalidSpectralWeighting,
+    /// Sound speed profile point with the given index has a non-positive or non-finite sound speed
+    InvalidSoundSpeed { index: usize },
+    /// There is no direct ray between the source and the receiver (e.g. in a shadow zone)
+    NoDirectPath,
+    /// Specified integration tolerance is not positive or not finite
+    InvalidTolerance { value: f64 },
+}
+
+impl fmt::Display for PhxError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PhxError::TooFewPoints { count } =>
+                write!(f, "tsProfile has to contain at least two points, got {}", count),
+            PhxError::InvalidStepCount { steps } =>
+                write!(f, "Specified number of intervals should be greater than zero, got {}", steps),
+            PhxError::PressureOutOfRange { value, min, max } =>
+                write!(f, "Specified pressure {} mBar is beyond the specified TS-profile [{}, {}] mBar", value, min, max),
+            PhxError::TimeOfFlightOutOfRange { value, min, max } =>
+                write!(f, "Specified time of flight {} s is beyond the specified TS-profile [{}, {}] s", value, min, max),
+            PhxError::NonMonotonicDepth { index } =>
+                write!(f, "Depth of the tsProfile point #{} is less than the depth of the previous point", index),
+            PhxError::NonFiniteValue { index } =>
+                write!(f, "tsProfile point #{} contains a non-finite value", index),
+            PhxError::NotAtSurface { z } =>
+                write!(f, "tsProfile has to start at the water surface, the shallowest point is at {} m", z),
+            PhxError::DepthOutOfRange { value, min, max } =>
+                write!(f, "Specified depth {} m is beyond the specified TS-profile [{}, {}] m", value, min, max),
+            PhxError::AngleOutOfRange { value, min, max } =>
+                write!(f, "Specified angle {} rad is beyond the allowed range [{}, {}) rad", value, min, max),
+            PhxError::InvalidBand { f_low, f_high } =>
+                write!(f, "Specified frequency band [{}, {}] kHz is invalid", f_low, f_high),
+            PhxError::InvalidSpectralWeighting =>
+                write!(f, "Spectral weighting should contain at least two finite non-negative samples with a positive sum"),
+            PhxError::InvalidSoundSpeed { index } =>
+                write!(f, "Sound speed profile point #{} has an invalid sound speed", index),
+            PhxError::NoDirectPath =>
+                write!(f, "There is no direct ray between the source and the receiver"),
+            PhxError::InvalidTolerance { value } =>
+                write!(f, "Specified tolerance should be positive and finite, got {}", value),
+        }
+    }
+}
+
+impl Error for PhxError {}
@@ -0,0 +1,140 @@
+// Gravity acceleration in the water column
+//
+// Normal gravity of the WGS84 ellipsoid by the Somigliana formula with the height correction:
+// NIMA TR8350.2 Department of Defense World Geodetic System 1984, 3rd ed., 2000, eqs. 4-1, 4-3
+//
+// Below the water surface gravity grows with depth by the free-air gradient
+// less the attraction of the overlying water layer (the Bouguer shell term):
+// dg/dz = 3.086E-6 - 4 pi G rho, about 2.2E-6 1/s^2
+
+use std::f64::consts::PI;
+
+use crate::phx_gravity_constant_wgs84_calc;
+
+const PHX_WGS84_A: f64 = 6378137.0;                // semi-major axis, m
+const PHX_WGS84_F: f64 = 1.0 / 298.257223563;      // flattening
+const PHX_WGS84_M: f64 = 0.00344978650684;         // w^2 a^2 b / GM
+
+const PHX_GRAVITATIONAL_CONSTANT: f64 = 6.67430E-11; // m^3/(kg s^2), CODATA 2018
+const PHX_FREE_AIR_GRADIENT: f64 = 3.086E-6;         // 1/s^2
+const PHX_MEAN_WATER_DENSITY: f64 = 1027.0;          // kg/m^3, density of the overlying water for the Bouguer term
+
+// Calculates the normal gravity at the given latitude and height above the WGS84 ellipsoid, m/s^2
+// lat_rad - latitude, rad
+// height - ellipsoidal height, m
+pub fn phx_gravity_somigliana_calc(lat_rad: f64, height: f64) -> f64 {
+
+    let sin_sq = lat_rad.sin().powi(2);
+    let g0 = phx_gravity_constant_wgs84_calc(lat_rad);
+
+    g0 * (1.0 - 2.0 / PHX_WGS84_A * (1.0 + PHX_WGS84_F + PHX_WGS84_M - 2.0 * PHX_WGS84_F * sin_sq) * height
+             + 3.0 * height * height / (PHX_WGS84_A * PHX_WGS84_A))
+}
+
+// Calculates the vertical gradient of gravity in the water column, 1/s^2 (per m of depth)
+// rho - density of the water, kg/m^3
+pub fn phx_gravity_depth_gradient_calc(rho: f64) -> f64 {
+    PHX_FREE_AIR_GRADIENT - 4.0 * PI * PHX_GRAVITATIONAL_CONSTANT * rho
+}
+
+// Calculates gravity at the given depth, m/s^2
+// g0 - gravity at the water surface, m/s^2
+// z - depth, m
+// rho - density of the overlying water, kg/m^3
+pub fn phx_gravity_at_depth_calc(g0: f64, z: f64, rho: f64) -> f64 {
+    g0 + phx_gravity_depth_gradient_calc(rho) * z
+}
+
+/// Gravity acceleration used by the TS-profile integrators
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GravityModel {
+    /// the same gravity (m/s^2) in the whole water column
+    Constant(f64),
+    /// gravity at the water surface (m/s^2), e.g. phx_gravity_constant_wgs84_calc, with the depth correction
+    DepthCorrected { g0: f64 },
+    /// Somigliana normal gravity at the latitude (rad) and the ellipsoidal height (m)
+    /// of the water surface, with the depth correction
+    Somigliana { lat_rad: f64, height: f64 },
+}
+
+impl From<f64> for GravityModel {
+    fn from(g: f64) -> GravityModel {
+        GravityModel::Constant(g)
+    }
+}
+
+impl GravityModel {
+
+    /// Gravity at the water surface, m/s^2
+    pub fn surface_gravity(&self) -> f64 {
+        match *self {
+            GravityModel::Constant(g) => g,
+            GravityModel::DepthCorrected { g0 } => g0,
+            GravityModel::Somigliana { lat_rad, height } => phx_gravity_somigliana_calc(lat_rad, height),
+        }
+    }
+
+    /// Vertical gradient of gravity below the water surface, 1/s^2
+    pub fn depth_gradient(&self) -> f64 {
+        match *self {
+            GravityModel::Constant(_) => 0.0,
+            _ => phx_gravity_depth_gradient_calc(PHX_MEAN_WATER_DENSITY),
+        }
+    }
+
+    /// Gravity at the given depth, m/s^2
+    /// z - depth, m
+    pub fn gravity_calc(&self, z: f64) -> f64 {
+        self.surface_gravity() + self.depth_gradient() * z
+    }
+
+    /// Gravity averaged over the water column between the surface and the given depth, m/s^2
+    /// z - depth, m
+    pub fn mean_gravity_calc(&self, z: f64) -> f64 {
+        self.surface_gravity() + 0.5 * self.depth_gradient() * z
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    #[test]
+    fn phx_gravity_somigliana_calc_test() {
+
+        // at the ellipsoid it is the sea-level normal gravity
+        for &lat_deg in [ 0.0, 30.0, 45.0, 60.0, 90.0 ].iter() {
+            let lat_rad = f64::to_radians(lat_deg);
+            assert_approx_eq!(phx_gravity_somigliana_calc(lat_rad, 0.0), phx_gravity_constant_wgs84_calc(lat_rad), 1E-12);
+        }
+
+        // free-air gradient of about 3.086E-6 1/s^2
+        let lat_rad = 45.0f64.to_radians();
+        assert_approx_eq!(phx_gravity_somigliana_calc(lat_rad, 0.0) - phx_gravity_somigliana_calc(lat_rad, 1000.0), 3.086E-3, 1E-5);
+        // Lake Titicaca, 3812 m
+        assert_approx_eq!(phx_gravity_somigliana_calc(-15.8f64.to_radians(), 3812.0), 9.7719, 1E-3);
+    }
+
+    #[test]
+    fn gravity_model_test() {
+
+        assert_approx_eq!(phx_gravity_depth_gradient_calc(1027.0), 2.225E-6, 1E-8);
+        assert_approx_eq!(phx_gravity_at_depth_calc(9.8, 10000.0, 1027.0), 9.8 + 2.225E-2, 1E-4);
+
+        let constant = GravityModel::from(9.81);
+        assert_eq!(constant, GravityModel::Constant(9.81));
+        assert_approx_eq!(constant.gravity_calc(5000.0), 9.81, 1E-12);
+
+        let lat_rad = 45.0f64.to_radians();
+        let g0 = phx_gravity_constant_wgs84_calc(lat_rad);
+        let corrected = GravityModel::DepthCorrected { g0 };
+        assert_approx_eq!(corrected.gravity_calc(0.0), g0, 1E-12);
+        assert_approx_eq!(corrected.gravity_calc(11000.0) - g0, 0.0245, 1E-3);
+        assert_approx_eq!(corrected.mean_gravity_calc(11000.0), 0.5 * (g0 + corrected.gravity_calc(11000.0)), 1E-12);
+
+        let somigliana = GravityModel::Somigliana { lat_rad, height: 0.0 };
+        assert_approx_eq!(somigliana.gravity_calc(4000.0), corrected.gravity_calc(4000.0), 1E-12);
+    }
+}
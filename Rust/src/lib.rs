@@ -1,5 +1,34 @@
 use std::f64;
 
+pub mod absorption;
+pub mod environment;
+mod error;
+pub mod gravity;
+pub mod noise;
+mod profile;
+pub mod pss78;
+pub mod raytrace;
+pub mod sonar;
+pub mod sound_speed;
+pub mod stability;
+pub mod teos10;
+pub mod thermo;
+pub mod transport;
+pub mod travel_time;
+pub mod unesco;
+pub mod units;
+
+pub use absorption::{AbsorptionModel, SpectralWeighting};
+pub use environment::Environment;
+pub use error::PhxError;
+pub use gravity::GravityModel;
+pub use noise::{NoiseModel, SurfaceNoise};
+pub use profile::{TsPoint, TsProfile};
+pub use raytrace::SoundSpeedProfile;
+pub use sonar::SpreadingLaw;
+pub use sound_speed::SoundSpeedModel;
+pub use travel_time::VerticalDirection;
+
 pub const PHX_FWTR_DENSITY_KGM3: f64        = 998.02;  // Fresh water density at 20°C
 pub const PHX_FWTR_SOUND_SPEED_MPS: f64     = 1500.0;  // Default speed of sound in water
 pub const PHX_FWTR_SOUND_SPEED_MPS_MIN: f64 = 1300.0;  // Min value for speed of sound
@@ -17,30 +46,102 @@ pub fn phx_linterp(x1: f64, y1: f64, x2: f64, y2: f64, x: f64) -> f64 {
     y1 + (x - x1)*(y2 - y1)/(x2 - x1)
 }
 
+// Terms of EOS-80 (Millero et al. 1980) as (value, d/dt, d/ds):
+// sig - one-atmosphere density less 1000 kg/m^3,
+// k0, a, b - coefficients of the secant bulk modulus K = k0 + a * p + b * p^2, bar
+pub(crate) struct Eos80Terms {
+    pub sig: (f64, f64, f64),
+    pub k0: (f64, f64, f64),
+    pub a: (f64, f64, f64),
+    pub b: (f64, f64, f64),
+}
+
+// Calculates the terms of EOS-80 and their derivatives by temperature (°C) and salinity (PSU)
+pub(crate) fn phx_eos80_terms_calc(t: f64, s: f64) -> Eos80Terms {
+
+    let sr = s.sqrt();
+
+    let sig = (((4.8314E-4 * s) +
+               ((-1.6546E-6 * t + 1.0227E-4) * t - 5.72466E-3) * sr +
+               (((5.3875E-9 * t - 8.2467E-7) * t + 7.6438E-5) * t - 4.0899E-3) * t + 0.824493) * s) +
+               ((((6.536332E-9 * t - 1.120083E-6) * t + 1.001685E-4) * t - 9.095290E-3) * t + 6.793952E-2) * t - 0.157406;
+    let sig_t = (((3.268166E-8 * t - 4.480332E-6) * t + 3.005055E-4) * t - 1.819058E-2) * t + 6.793952E-2 +
+                (((2.155E-8 * t - 2.47401E-6) * t + 1.52876E-4) * t - 4.0899E-3) * s +
+                (-3.3092E-6 * t + 1.0227E-4) * s * sr;
+    let sig_s = (((5.3875E-9 * t - 8.2467E-7) * t + 7.6438E-5) * t - 4.0899E-3) * t + 0.824493 +
+                1.5 * ((-1.6546E-6 * t + 1.0227E-4) * t - 5.72466E-3) * sr + 9.6628E-4 * s;
+
+    let k0 = (((((-5.3009E-4 * t + 1.6483E-2) * t + 7.944E-2) * sr) +
+              ((-6.1670E-5 * t + 1.09987E-2) * t - 0.603459) * t + 54.6746) * s) +
+               (((-5.155288E-5 * t + 1.360477E-2) * t - 2.327105) * t + 148.4206) * t + 19652.21;
+    let k0_t = ((-2.0622152E-4 * t + 4.081431E-2) * t - 4.65421) * t + 148.4206 +
+               ((-1.8501E-4 * t + 2.19974E-2) * t - 0.603459) * s +
+               (-1.06018E-3 * t + 1.6483E-2) * s * sr;
+    let k0_s = ((-6.1670E-5 * t + 1.09987E-2) * t - 0.603459) * t + 54.6746 +
+               1.5 * ((-5.3009E-4 * t + 1.6483E-2) * t + 7.944E-2) * sr;
+
+    let a = (1.91075E-4 * sr + (-1.6078E-6 * t - 1.0981E-5) * t + 2.2838E-3) * s +
+             ((-5.77905E-7 * t + 1.16092E-4) * t + 1.43713E-3) * t + 3.239908;
+    let a_t = (-3.2156E-6 * t - 1.0981E-5) * s + (-1.733715E-6 * t + 2.32184E-4) * t + 1.43713E-3;
+    let a_s = 2.866125E-4 * sr + (-1.6078E-6 * t - 1.0981E-5) * t + 2.2838E-3;
+
+    let b = ((9.1697E-10 * t + 2.0816E-8) * t - 9.9348E-7) * s + (5.2787E-8 * t - 6.12293E-6) * t + 8.50935E-5;
+    let b_t = (1.83394E-9 * t + 2.0816E-8) * s + 1.05574E-7 * t - 6.12293E-6;
+    let b_s = (9.1697E-10 * t + 2.0816E-8) * t - 9.9348E-7;
+
+    Eos80Terms { sig: (sig, sig_t, sig_s), k0: (k0, k0_t, k0_s), a: (a, a_t, a_s), b: (b, b_t, b_s) }
+}
+
 /// calculates in situ density of water
 /// millero et al 1980, deep-sea res.,27a,255-264
 /// jpots ninth report 1978,tenth report 1980
 pub fn phx_water_density_calc(t_c: f64, p_mbar: f64, s_psu: f64) -> f64 {
 
     let p = p_mbar / 1000.0;
-    let sr = s_psu.sqrt();
-    let sig = (((4.8314E-4 * s_psu) +
-               ((-1.6546E-6 * t_c + 1.0227E-4) * t_c - 5.72466E-3) * sr +
-               (((5.3875E-9 * t_c - 8.2467E-7) * t_c + 7.6438E-5) * t_c - 4.0899E-3) * t_c + 0.824493) * s_psu) +
-               ((((6.536332E-9 * t_c - 1.120083E-6) * t_c + 1.001685E-4) * t_c - 9.095290E-3) * t_c + 6.793952E-2) * t_c - 0.157406;
+    let terms = phx_eos80_terms_calc(t_c, s_psu);
+    let k = (terms.b.0 * p + terms.a.0) * p + terms.k0.0;
+
+    1000.0 + (k * terms.sig.0 + 1000.0 * p) / (k - p)
+}
 
-    let b = ((9.1697E-10 * t_c + 2.0816E-8) * t_c - 9.9348E-7) * s_psu + (5.2787E-8 * t_c - 6.12293E-6) * t_c + 8.50935E-5;
+/// calculates secant bulk modulus of water, mBar
+/// of the same equation of state as phx_water_density_calc
+/// millero et al 1980, deep-sea res.,27a,255-264
+pub fn phx_secant_bulk_modulus_calc(t_c: f64, p_mbar: f64, s_psu: f64) -> f64 {
 
-    let k0 = (((((-5.3009E-4 * t_c + 1.6483E-2) * t_c + 7.944E-2) * sr) + 
-              ((-6.1670E-5 * t_c + 1.09987E-2) * t_c - 0.603459) * t_c + 54.6746) * s_psu) +
-               (((-5.155288E-5 * t_c + 1.360477E-2) * t_c - 2.327105) * t_c + 148.4206) * t_c + 19652.21;
+    let p = p_mbar / 1000.0;
+    let terms = phx_eos80_terms_calc(t_c, s_psu);
 
-    let a = (1.91075E-4 * sr + (-1.6078E-6 * t_c - 1.0981E-5) * t_c + 2.2838E-3) * s_psu +
-             ((-5.77905E-7 * t_c + 1.16092E-4) * t_c + 1.43713E-3) * t_c + 3.239908;
+    ((terms.b.0 * p + terms.a.0) * p + terms.k0.0) * 1000.0
+}
 
-    let k = (b * p + a) * p + k0;
+/// Equation of state used to calculate in situ density of water
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum EquationOfState {
+    /// Millero et al. 1980 (EOS-80), see phx_water_density_calc
+    #[default]
+    Eos80,
+    /// TEOS-10 75-term polynomial, see teos10 module.
+    /// Practical salinity is converted to Reference Salinity,
+    /// in situ temperature - to Conservative Temperature
+    Teos10,
+}
 
-    (1000.0 + (k * sig + 1000.0 * p) / (k - p))
+impl EquationOfState {
+
+    /// calculates in situ density of water, kg/m^3
+    /// t - temperature, °C
+    /// p - pressure, mBar
+    /// s - salinity, PSU
+    pub fn density_calc(&self, t: f64, p: f64, s: f64) -> f64 {
+        match *self {
+            EquationOfState::Eos80 => phx_water_density_calc(t, p, s),
+            EquationOfState::Teos10 => {
+                let sa = teos10::phx_teos10_sa_from_sp_calc(s, 0.0);
+                teos10::phx_teos10_density_calc(sa, teos10::phx_teos10_ct_from_t_calc(sa, t, p), p)
+            },
+        }
+    }
 }
 
 /// The UNESCO equation: Chen and Millero (1977)
@@ -92,7 +193,7 @@ pub fn phx_speed_of_sound_unesco_calc(t: f64, p: f64, s: f64) -> f64 {
     let c_0 = ((((3.1464E-9  * t - 1.47800E-6) * t + 3.3420E-4) * t - 5.80852E-2) * t + 5.03711) * t + 1402.388;
     let c  = ((c_3 * p + c_2) * p + c_1) * p + c_0;
 
-    (c + (a + b * sr + d * s) * s)
+    c + (a + b * sr + d * s) * s
 }
 
 /// Calculates gravity at sea level vs latitude
@@ -100,13 +201,13 @@ pub fn phx_speed_of_sound_unesco_calc(t: f64, p: f64, s: f64) -> f64 {
 pub fn phx_gravity_constant_wgs84_calc(lat_rad: f64) -> f64 {
 
     let phi_sq = lat_rad.sin().powi(2);    
-    (PHX_GE * ((1.0 + PHX_K * phi_sq) / (1.0 - PHX_E * phi_sq).sqrt()))
+    PHX_GE * ((1.0 + PHX_K * phi_sq) / (1.0 - PHX_E * phi_sq).sqrt())
 }
 
 /// calculates distance from the water surface where pressure is p0 to the point, where pressure is p
 pub fn phx_depth_by_pressure_calc(p_mbar: f64, p0_mbar: f64, rho_kg_by_m3: f64, g_m_by_s2: f64) -> f64 {
 
-    (100.0 * (p_mbar - p0_mbar) / (rho_kg_by_m3 * g_m_by_s2))
+    100.0 * (p_mbar - p0_mbar) / (rho_kg_by_m3 * g_m_by_s2)
 }
 
 
@@ -120,42 +221,85 @@ pub fn phx_pressure_by_depth_calc(h: f64, p0: f64, rho: f64, g: f64) -> f64 {
     h * rho * g / 100.0 + p0
 }
   
+// Checks the number of integration intervals of the TS-profile based integrators
+fn phx_check_step_count(n: i32) -> Result<(), PhxError> {
+
+    if n <= 0 {
+        return Err(PhxError::InvalidStepCount { steps: n });
+    }
+
+    Ok(())
+}
+
+// Splits the depth range between z1 and z2 (in any order) into the TS-profile layers
+// and distributes n_z integration intervals over them in proportion to their thickness,
+// so that no interval crosses a TS-profile point
+// Returns (top depth, bottom depth, number of intervals) for every layer, ordered by depth
+pub(crate) fn phx_ts_profile_layers_split(ts_profile: &TsProfile, z1: f64, z2: f64, n_z: i32) -> Vec<(f64, f64, i32)> {
+
+    let (z_top, z_bottom) = (z1.min(z2), z1.max(z2));
+    if z_top == z_bottom {
+        return Vec::new();
+    }
+
+    let mut bounds = vec![ z_top ];
+    bounds.extend(ts_profile.points().iter().map(|p| p.z).filter(|&z| z > z_top && z < z_bottom));
+    bounds.push(z_bottom);
+
+    bounds.windows(2).map(|layer| {
+        let n = ((n_z as f64) * (layer[1] - layer[0]) / (z_bottom - z_top)).ceil().max(1.0) as i32;
+        (layer[0], layer[1], n)
+    }).collect()
+}
+
+// Builds a TS-profile from the tuples passed to the panicking integrators
+pub(crate) fn phx_ts_profile_from_tuples(ts_profile: &[(f64, f64, f64)]) -> TsProfile {
+
+    match TsProfile::from_ordered(ts_profile.iter().map(|&p| TsPoint::from(p)).collect()) {
+        Ok(profile) => profile,
+        Err(e) => panic!("{}", e),
+    }
+}
+
 // Calculates depth (as a distance between the water surface and a point with
 // the given pressure) by the specified TS-profile
 // pm - pressure measured at the point, mBar
 // p0 - atmospheric pressure, mBar
 // g - gravity acceleration, m/s^2
 // tsProfile - vertical Temperature-Salinity profile at the given point
-// as an array of (f64, f64, f64)
 //   z - vertical coordinate, m (positive, 0 - water surface)
 //   t - temperature, °C
 //   s - salinity, PSU
 // Np - number of pressure intervals for integration
-pub fn phx_depth_by_pressure_ts_profile(pm: f64, p0: f64, g: f64, ts_profile: &[(f64, f64, f64)], n_p: i32) -> f64 {
+// eos - equation of state to calculate water density
+// Returns an error if the arguments are invalid instead of panicking
+pub fn phx_try_depth_by_pressure_ts_profile(pm: f64, p0: f64, g: f64, ts_profile: &TsProfile, n_p: i32, eos: EquationOfState) -> Result<f64, PhxError> {
+    phx_try_depth_by_pressure_ts_profile_gravity_model(pm, p0, GravityModel::Constant(g), ts_profile, n_p, eos)
+}
 
-    if n_p <= 0 {
-        panic!("Specified number of time intervals Nt should be greater than zero");
-    }
+// Calculates depth by the specified TS-profile, see phx_try_depth_by_pressure_ts_profile
+// g - gravity model of the water column
+pub fn phx_try_depth_by_pressure_ts_profile_gravity_model(pm: f64, p0: f64, g: GravityModel, ts_profile: &TsProfile, n_p: i32, eos: EquationOfState) -> Result<f64, PhxError> {
 
-    if ts_profile.len() < 2 {
-        panic!("tsProfile has to contain at least two points");
-    }
+    phx_check_step_count(n_p)?;
 
-    let mut t1 = ts_profile[0].1;
-    let mut s1 = ts_profile[0].2;
-    let rho0 = phx_water_density_calc(t1, p0, s1);
-    let mut p1 = phx_pressure_by_depth_calc(ts_profile[0].0, p0, rho0, g);
+    let pts = ts_profile.points();
+    let mut t1 = pts[0].t;
+    let mut s1 = pts[0].s;
+    let rho0 = eos.density_calc(t1, p0, s1);
+    let mut p1 = phx_pressure_by_depth_calc(pts[0].z, p0, rho0, g.mean_gravity_calc(pts[0].z));
 
-    let pe = phx_pressure_by_depth_calc(ts_profile[ts_profile.len() - 1].0, p0, rho0, g);
+    let ps = p1;
+    let pe = phx_pressure_by_depth_calc(ts_profile.max_depth(), p0, rho0, g.mean_gravity_calc(ts_profile.max_depth()));
 
-    if (pm < p1) || (pm > pe) {
-        panic!("Specified pressure is beyond the specified TS-profile");
+    if !(pm >= ps && pm <= pe) {
+        return Err(PhxError::PressureOutOfRange { value: pm, min: ps, max: pe });
     }
 
     let mut p_idx = 1;
-    let mut t2 = ts_profile[p_idx].1;
-    let mut s2 = ts_profile[p_idx].2;
-    let mut p2 = phx_pressure_by_depth_calc(ts_profile[p_idx].0, p0, rho0, g);
+    let mut t2 = pts[p_idx].t;
+    let mut s2 = pts[p_idx].s;
+    let mut p2 = phx_pressure_by_depth_calc(pts[p_idx].z, p0, rho0, g.mean_gravity_calc(pts[p_idx].z));
 
     let dp = (pm - p0) / (n_p as f64);
     let mut h = 0.0;
@@ -165,105 +309,515 @@ pub fn phx_depth_by_pressure_ts_profile(pm: f64, p0: f64, g: f64, ts_profile: &[
     let mut p = p0;
     let mut s;    
     
-    while p < pm {
+    // exactly n_p steps, comparing the accumulated pressure with pm can add one more
+    for _ in 0..n_p {
 
         p += dp;
 
-        if p > p2 {
+        // the pressure steps can overshoot the deepest point for pm close to pe,
+        // the last layer is used then
+        if p > p2 && p_idx + 1 < pts.len() {
 
             p1 = p2;
             t1 = t2;
             s1 = s2;
             p_idx += 1;
 
-            t2 = ts_profile[p_idx].1;
-            s2 = ts_profile[p_idx].2;
-            p2 = phx_pressure_by_depth_calc(ts_profile[p_idx].0, p0, rho0, g);
+            t2 = pts[p_idx].t;
+            s2 = pts[p_idx].s;
+            p2 = phx_pressure_by_depth_calc(pts[p_idx].z, p0, rho0, g.mean_gravity_calc(pts[p_idx].z));
         }
 
         t = phx_linterp(p1, t1, p2, t2, p);
         s = phx_linterp(p1, s1, p2, s2, p);
 
-        rho = phx_water_density_calc(t, p, s);
-        h += 1.0 / rho;
+        rho = eos.density_calc(t, p, s);
+        h += 100.0 * dp / (rho * g.gravity_calc(h));
     }
 
-    (h * 100.0 * dp / g)
+    Ok(h)
+}
+
+// Calculates depth by the specified TS-profile, see phx_try_depth_by_pressure_ts_profile
+// tsProfile - as an array of (f64, f64, f64) ordered by depth
+// Panics if the arguments are invalid
+pub fn phx_depth_by_pressure_ts_profile(pm: f64, p0: f64, g: f64, ts_profile: &[(f64, f64, f64)], n_p: i32) -> f64 {
+
+    match phx_try_depth_by_pressure_ts_profile(pm, p0, g, &phx_ts_profile_from_tuples(ts_profile), n_p, EquationOfState::Eos80) {
+        Ok(h) => h,
+        Err(e) => panic!("{}", e),
+    }
 }
   
+// Calculates hydrostatic pressure at the given depth by the specified TS-profile,
+// the inverse of phx_try_depth_by_pressure_ts_profile,
+// dp/dz = rho(t, s, p) * g is integrated by the fourth order Runge-Kutta method
+// z - depth, m
+// p0 - atmospheric pressure, mBar
+// g - gravity acceleration, m/s^2
+// tsProfile - vertical Temperature-Salinity profile at the given point
+// Nz - number of depth intervals for integration
+// eos - equation of state to calculate water density
+// Returns pressure in mBar or an error if the arguments are invalid
+pub fn phx_try_pressure_by_depth_ts_profile(z: f64, p0: f64, g: f64, ts_profile: &TsProfile, n_z: i32, eos: EquationOfState) -> Result<f64, PhxError> {
+    phx_try_pressure_by_depth_ts_profile_gravity_model(z, p0, GravityModel::Constant(g), ts_profile, n_z, eos)
+}
+
+// Calculates pressure at depth by the specified TS-profile, see phx_try_pressure_by_depth_ts_profile
+// g - gravity model of the water column
+pub fn phx_try_pressure_by_depth_ts_profile_gravity_model(z: f64, p0: f64, g: GravityModel, ts_profile: &TsProfile, n_z: i32, eos: EquationOfState) -> Result<f64, PhxError> {
+
+    phx_check_step_count(n_z)?;
+
+    let z_max = ts_profile.max_depth();
+    if !(z >= 0.0 && z <= z_max) {
+        return Err(PhxError::DepthOutOfRange { value: z, min: 0.0, max: z_max });
+    }
+
+    Ok(phx_pressure_by_depth_rk4(0.0, p0, z, n_z, g, ts_profile, eos))
+}
+
+// Integrates pressure from (z1, p1) down to z2 in n equal steps, mBar
+fn phx_pressure_by_depth_rk4(z1: f64, p1: f64, z2: f64, n: i32, g: GravityModel, ts_profile: &TsProfile, eos: EquationOfState) -> f64 {
+
+    // mBar per m
+    let dp_dz = |z: f64, p: f64| {
+        let pt = ts_profile.interpolate(z);
+        eos.density_calc(pt.t, p, pt.s) * g.gravity_calc(z) / 100.0
+    };
+
+    let dz = (z2 - z1) / (n as f64);
+    let mut p = p1;
+
+    for i in 0..n {
+        let z = z1 + (i as f64) * dz;
+        let k1 = dp_dz(z, p);
+        let k2 = dp_dz(z + 0.5 * dz, p + 0.5 * dz * k1);
+        let k3 = dp_dz(z + 0.5 * dz, p + 0.5 * dz * k2);
+        let k4 = dp_dz(z + dz, p + dz * k3);
+        p += dz * (k1 + 2.0 * k2 + 2.0 * k3 + k4) / 6.0;
+    }
+
+    p
+}
+
+// Calculates pressure at depth by the specified TS-profile, see phx_try_pressure_by_depth_ts_profile
+// tsProfile - as an array of (f64, f64, f64) ordered by depth
+// Panics if the arguments are invalid
+pub fn phx_pressure_by_depth_ts_profile(z: f64, p0: f64, g: f64, ts_profile: &[(f64, f64, f64)], n_z: i32) -> f64 {
+
+    match phx_try_pressure_by_depth_ts_profile(z, p0, g, &phx_ts_profile_from_tuples(ts_profile), n_z, EquationOfState::Eos80) {
+        Ok(p) => p,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+/// Precomputed hydrostatic pressure-depth table of a TS-profile,
+/// which converts depth to pressure and back by linear interpolation
+#[derive(Debug, Clone, PartialEq)]
+pub struct PressureDepthTable {
+    /// (depth, m; pressure, mBar) ordered by depth
+    points: Vec<(f64, f64)>,
+}
+
+impl PressureDepthTable {
+
+    /// Builds the table by integration of the hydrostatic equation,
+    /// see phx_try_pressure_by_depth_ts_profile
+    /// p0 - atmospheric pressure, mBar
+    /// g - gravity acceleration, m/s^2
+    /// ts_profile - vertical Temperature-Salinity profile
+    /// n_z - number of depth intervals of the table over the whole profile
+    /// eos - equation of state to calculate water density
+    pub fn try_new(p0: f64, g: f64, ts_profile: &TsProfile, n_z: i32, eos: EquationOfState) -> Result<PressureDepthTable, PhxError> {
+        PressureDepthTable::try_new_gravity_model(p0, GravityModel::Constant(g), ts_profile, n_z, eos)
+    }
+
+    /// Builds the table with the given gravity model, see PressureDepthTable::try_new
+    /// g - gravity model of the water column
+    pub fn try_new_gravity_model(p0: f64, g: GravityModel, ts_profile: &TsProfile, n_z: i32, eos: EquationOfState) -> Result<PressureDepthTable, PhxError> {
+
+        phx_check_step_count(n_z)?;
+
+        // the table includes the profile points, each layer is divided into equal intervals
+        let dz = ts_profile.max_depth() / (n_z as f64);
+        let mut points = vec![ (0.0, p0) ];
+        for pair in ts_profile.points().windows(2) {
+
+            let (z1, z2) = (pair[0].z, pair[1].z);
+            if z2 <= z1 {
+                continue;
+            }
+
+            let n = ((z2 - z1) / dz).ceil().max(1.0) as i32;
+            let (mut z, mut p) = (z1, points[points.len() - 1].1);
+            for i in 1..=n {
+                let z_next = z1 + (z2 - z1) * (i as f64) / (n as f64);
+                p = phx_pressure_by_depth_rk4(z, p, z_next, 1, g, ts_profile, eos);
+                z = z_next;
+                points.push((z, p));
+            }
+        }
+
+        Ok(PressureDepthTable { points })
+    }
+
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+
+    /// Depth of the deepest point of the table, m
+    pub fn max_depth(&self) -> f64 {
+        self.points[self.points.len() - 1].0
+    }
+
+    /// Pressure at the deepest point of the table, mBar
+    pub fn max_pressure(&self) -> f64 {
+        self.points[self.points.len() - 1].1
+    }
+
+    /// Pressure at the given depth, mBar
+    /// z - depth, m
+    pub fn try_pressure_calc(&self, z: f64) -> Result<f64, PhxError> {
+
+        let z_max = self.max_depth();
+        if !(z >= 0.0 && z <= z_max) {
+            return Err(PhxError::DepthOutOfRange { value: z, min: 0.0, max: z_max });
+        }
+
+        let idx = self.points.partition_point(|pt| pt.0 < z).clamp(1, self.points.len() - 1);
+        let ((z1, p1), (z2, p2)) = (self.points[idx - 1], self.points[idx]);
+        Ok(phx_linterp(z1, p1, z2, p2, z))
+    }
+
+    /// Depth at the given pressure, m
+    /// p - pressure, mBar
+    pub fn try_depth_calc(&self, p: f64) -> Result<f64, PhxError> {
+
+        let (p_min, p_max) = (self.points[0].1, self.max_pressure());
+        if !(p >= p_min && p <= p_max) {
+            return Err(PhxError::PressureOutOfRange { value: p, min: p_min, max: p_max });
+        }
+
+        let idx = self.points.partition_point(|pt| pt.1 < p).clamp(1, self.points.len() - 1);
+        let ((z1, p1), (z2, p2)) = (self.points[idx - 1], self.points[idx]);
+        Ok(phx_linterp(p1, z1, p2, z2, p))
+    }
+}
+
+/// Tolerance of an adaptive depth calculation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DepthTolerance {
+    /// absolute tolerance, m
+    Absolute(f64),
+    /// tolerance relative to the depth
+    Relative(f64),
+}
+
+/// Depth calculated by the adaptive integration
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DepthEstimate {
+    /// depth, m
+    pub depth: f64,
+    /// estimate of the absolute integration error, m
+    pub error: f64,
+    /// number of the water density evaluations
+    pub evaluations: usize,
+}
+
+const PHX_ADAPTIVE_MAX_LEVEL: u32 = 40; // maximal recursion level of the adaptive Simpson integration
+
+// Adaptive Simpson integration over [a, b] with the known values at a, (a + b) / 2 and b
+// and the Simpson estimate of the whole interval, returns the integral and its error estimate
+fn phx_adaptive_simpson<F: FnMut(f64) -> f64>(f: &mut F, (a, fa): (f64, f64), (m, fm): (f64, f64), (b, fb): (f64, f64),
+                                             whole: f64, tol: f64, level: u32) -> (f64, f64) {
+
+    let (lm, rm) = (0.5 * (a + m), 0.5 * (m + b));
+    let (flm, frm) = (f(lm), f(rm));
+    let left = (m - a) / 6.0 * (fa + 4.0 * flm + fm);
+    let right = (b - m) / 6.0 * (fm + 4.0 * frm + fb);
+    let delta = left + right - whole;
+
+    // Richardson extrapolation, the error of the refined estimate is |delta| / 15
+    if level >= PHX_ADAPTIVE_MAX_LEVEL || delta.abs() <= 15.0 * tol {
+        return (left + right + delta / 15.0, delta.abs() / 15.0);
+    }
+
+    let (l, l_err) = phx_adaptive_simpson(f, (a, fa), (lm, flm), (m, fm), left, 0.5 * tol, level + 1);
+    let (r, r_err) = phx_adaptive_simpson(f, (m, fm), (rm, frm), (b, fb), right, 0.5 * tol, level + 1);
+    (l + r, l_err + r_err)
+}
+
+// Calculates depth (as a distance between the water surface and a point with
+// the given pressure) by the specified TS-profile with the adaptive Simpson integration
+// of 1/(rho g) over each profile layer, see phx_try_depth_by_pressure_ts_profile
+// pm - pressure measured at the point, mBar
+// p0 - atmospheric pressure, mBar
+// g - gravity acceleration, m/s^2
+// tsProfile - vertical Temperature-Salinity profile at the given point
+// tolerance - required absolute or relative tolerance of the depth
+// eos - equation of state to calculate water density
+// Returns the depth with its error estimate and the number of density evaluations
+pub fn phx_try_depth_by_pressure_adaptive_ts_profile(pm: f64, p0: f64, g: f64, ts_profile: &TsProfile,
+                                                     tolerance: DepthTolerance, eos: EquationOfState) -> Result<DepthEstimate, PhxError> {
+    phx_try_depth_by_pressure_adaptive_ts_profile_gravity_model(pm, p0, GravityModel::Constant(g), ts_profile, tolerance, eos)
+}
+
+// Calculates depth by the specified TS-profile, see phx_try_depth_by_pressure_adaptive_ts_profile
+// g - gravity model of the water column
+pub fn phx_try_depth_by_pressure_adaptive_ts_profile_gravity_model(pm: f64, p0: f64, g: GravityModel, ts_profile: &TsProfile,
+                                                                   tolerance: DepthTolerance, eos: EquationOfState) -> Result<DepthEstimate, PhxError> {
+
+    let tol = match tolerance {
+        DepthTolerance::Absolute(tol) | DepthTolerance::Relative(tol) => tol,
+    };
+
+    if !(tol > 0.0 && tol.is_finite()) {
+        return Err(PhxError::InvalidTolerance { value: tol });
+    }
+
+    let pts = ts_profile.points();
+    let rho0 = eos.density_calc(pts[0].t, p0, pts[0].s);
+    let ps = phx_pressure_by_depth_calc(pts[0].z, p0, rho0, g.mean_gravity_calc(pts[0].z));
+    let pe = phx_pressure_by_depth_calc(ts_profile.max_depth(), p0, rho0, g.mean_gravity_calc(ts_profile.max_depth()));
+
+    if !(pm >= ps && pm <= pe) {
+        return Err(PhxError::PressureOutOfRange { value: pm, min: ps, max: pe });
+    }
+
+    // depth = 100 * integral(dp / (rho * g)), m
+    // gravity is taken at the depth estimated with the surface density and gravity
+    let k = 100.0;
+    let g0 = g.surface_gravity();
+    let mut evaluations = 0;
+    let mut depth = 0.0;
+    let mut error = 0.0;
+
+    for pair in pts.windows(2) {
+
+        let p1 = phx_pressure_by_depth_calc(pair[0].z, p0, rho0, g.mean_gravity_calc(pair[0].z));
+        let p2 = phx_pressure_by_depth_calc(pair[1].z, p0, rho0, g.mean_gravity_calc(pair[1].z));
+        let (a, b) = (p1.max(p0), p2.min(pm));
+
+        if b > a {
+
+            let mut f = |p: f64| {
+                evaluations += 1;
+                let rho = eos.density_calc(phx_linterp(p1, pair[0].t, p2, pair[1].t, p),
+                                           p,
+                                           phx_linterp(p1, pair[0].s, p2, pair[1].s, p));
+                1.0 / (rho * g.gravity_calc(phx_depth_by_pressure_calc(p, p0, rho0, g0)))
+            };
+
+            let m = 0.5 * (a + b);
+            let (fa, fm, fb) = (f(a), f(m), f(b));
+            let whole = (b - a) / 6.0 * (fa + 4.0 * fm + fb);
+
+            // the tolerance is shared between the layers in proportion to their thickness
+            let layer_tol = match tolerance {
+                DepthTolerance::Absolute(tol) => tol * (b - a) / (pm - p0),
+                DepthTolerance::Relative(tol) => tol * k * whole,
+            };
+
+            let (h, h_err) = phx_adaptive_simpson(&mut f, (a, fa), (m, fm), (b, fb), whole, layer_tol / k, 0);
+            depth += k * h;
+            error += k * h_err;
+        }
+
+        if p2 >= pm {
+            break;
+        }
+    }
+
+    Ok(DepthEstimate { depth, error, evaluations })
+}
+
 // Calculates the path, which sound traveled in vertical direction
 // between the water surface and the deepest point during
 // a given time of flight considering given temperature and salinity profile
 // tof - time of flight, sec
 // Nt - number of time intervals for integration
 // tsProfile - vertical Temperature-Salinity profile at the given point
-// as an array of TSPoint
 //   z - vertical coordinate, m (positive, 0 - water surface)
 //   t - temperature, °C
 //   s - salinity, PSU
-pub fn phx_vertical_sound_path_ts_profile(tof: f64, n_t: i32, g: f64, ts_profile: &[(f64, f64, f64)]) -> f64 {
-      
-    if ts_profile.len() < 2 {
-      panic!("tsProfile has to contain at least two points");
-    }
-  
-    if n_t <= 0 {
-      panic!("Specified number of time intervals Nt should be greater than zero");
-    }
-        
-    let mut z1 = ts_profile[0].0;
-    let mut t1 = ts_profile[0].1;
-    let mut s1 = ts_profile[0].2;
-    let rho0 = phx_water_density_calc(t1, PHX_ATM_PRESSURE_MBAR, s1);
-    let mut p1 = phx_pressure_by_depth_calc(z1, PHX_ATM_PRESSURE_MBAR, rho0, g);
+// eos - equation of state to calculate water density
+// model - sound speed equation
+// Returns an error if the arguments are invalid instead of panicking
+pub fn phx_try_vertical_sound_path_ts_profile(tof: f64, n_t: i32, g: f64, ts_profile: &TsProfile, eos: EquationOfState, model: SoundSpeedModel) -> Result<f64, PhxError> {
+    phx_try_vertical_sound_path_ts_profile_gravity_model(tof, n_t, GravityModel::Constant(g), ts_profile, eos, model)
+}
+
+// Calculates the vertical path of sound, see phx_try_vertical_sound_path_ts_profile
+// g - gravity model of the water column
+pub fn phx_try_vertical_sound_path_ts_profile_gravity_model(tof: f64, n_t: i32, g: GravityModel, ts_profile: &TsProfile, eos: EquationOfState, model: SoundSpeedModel) -> Result<f64, PhxError> {
+
+    phx_check_step_count(n_t)?;
+
+    let pts = ts_profile.points();
+    let mut z1 = pts[0].z;
+    let mut t1 = pts[0].t;
+    let mut s1 = pts[0].s;
+    let rho0 = eos.density_calc(t1, PHX_ATM_PRESSURE_MBAR, s1);
+    let mut p1 = phx_pressure_by_depth_calc(z1, PHX_ATM_PRESSURE_MBAR, rho0, g.mean_gravity_calc(z1));
   
-    let mut v = phx_speed_of_sound_unesco_calc(t1, p1, s1);
+    let mut v = model.speed_calc(t1, p1, s1, z1);
+
+    // the exact time of flight to the deepest point, sound speed can grow with depth,
+    // so the surface sound speed would overestimate it
+    let tof_max = phx_ts_profile_sound_layers(ts_profile, g, eos, model).iter().map(|l| l.3).sum::<f64>();
   
-    if v * tof > ts_profile[ts_profile.len() - 1].0 {
-      panic!("Specified time of flight is beyond the specified TS-profile");
+    if !(tof >= 0.0 && tof <= tof_max) {
+        return Err(PhxError::TimeOfFlightOutOfRange { value: tof, min: 0.0, max: tof_max });
     }
   
     let mut p_idx = 1;
-    let mut z2 = ts_profile[p_idx].0;
-    let mut t2 = ts_profile[p_idx].1;
-    let mut s2 = ts_profile[p_idx].2;
-    let mut p2 = phx_pressure_by_depth_calc(z2, PHX_ATM_PRESSURE_MBAR, rho0, g);
+    let mut z2 = pts[p_idx].z;
+    let mut t2 = pts[p_idx].t;
+    let mut s2 = pts[p_idx].s;
+    let mut p2 = phx_pressure_by_depth_calc(z2, PHX_ATM_PRESSURE_MBAR, rho0, g.mean_gravity_calc(z2));
   
     let dt = tof / (n_t as f64);
     let mut h = 0.0;
     let mut t;
     let mut p;
     let mut s;
-    let mut tt = 0.0;
   
-    while tt < tof {
+    // exactly n_t steps, comparing the accumulated time with tof can add one more
+    for _ in 0..n_t {
   
-        tt += dt;
-        h = h + dt * v;
+        h += dt * v;
   
         if h > z2 {
+
+            // the time steps can overshoot the deepest point, which is reached not earlier than tof_max
+            if p_idx + 1 >= pts.len() {
+                h = z2;
+                break;
+            }
   
             p1 = p2;
             t1 = t2;
             s1 = s2;
             z1 = z2;
-            p_idx = p_idx + 1;
+            p_idx += 1;
   
-            z2 = ts_profile[p_idx].0;
-            t2 = ts_profile[p_idx].1;
-            s2 = ts_profile[p_idx].2;
-            p2 = phx_pressure_by_depth_calc(z2, PHX_ATM_PRESSURE_MBAR + p1, rho0, g);
+            z2 = pts[p_idx].z;
+            t2 = pts[p_idx].t;
+            s2 = pts[p_idx].s;
+            p2 = phx_pressure_by_depth_calc(z2, PHX_ATM_PRESSURE_MBAR, rho0, g.mean_gravity_calc(z2));
         }
   
         t = phx_linterp(z1, t1, z2, t2, h);
         p = phx_linterp(z1, p1, z2, p2, h);
         s = phx_linterp(z1, s1, z2, s2, h);
-        v = phx_speed_of_sound_unesco_calc(t, p, s);
+        v = model.speed_calc(t, p, s, h);
     }
   
-    h
+    Ok(h)
+}
+
+// Calculates the vertical path of sound, see phx_try_vertical_sound_path_ts_profile
+// tsProfile - as an array of (f64, f64, f64) ordered by depth
+// Panics if the arguments are invalid
+pub fn phx_vertical_sound_path_ts_profile(tof: f64, n_t: i32, g: f64, ts_profile: &[(f64, f64, f64)]) -> f64 {
+
+    match phx_try_vertical_sound_path_ts_profile(tof, n_t, g, &phx_ts_profile_from_tuples(ts_profile), EquationOfState::Eos80, SoundSpeedModel::ChenMillero) {
+        Ok(h) => h,
+        Err(e) => panic!("{}", e),
+    }
 }
   
+// Calculates pressure at the given depth the way the vertical sound path integrators do:
+// absolute hydrostatic pressure with the density of the water at the surface point, mBar
+// ts_profile - vertical Temperature-Salinity profile
+// z - depth, m
+// g - gravity model of the water column
+// eos - equation of state to calculate water density
+pub(crate) fn phx_ts_profile_pressure_calc(ts_profile: &TsProfile, z: f64, g: GravityModel, eos: EquationOfState) -> f64 {
+
+    let surface = ts_profile.points()[0];
+    let rho0 = eos.density_calc(surface.t, PHX_ATM_PRESSURE_MBAR, surface.s);
+    phx_pressure_by_depth_calc(z, PHX_ATM_PRESSURE_MBAR, rho0, g.mean_gravity_calc(z))
+}
+
+// Calculates the layers of a TS-profile with the sound speed varying linearly with depth
+// between the profile points, as (top depth, sound speed at the top, speed gradient, travel time)
+// The travel time through a layer is t = ln(c2 / c1) * (z2 - z1) / (c2 - c1)
+fn phx_ts_profile_sound_layers(ts_profile: &TsProfile, g: GravityModel, eos: EquationOfState, model: SoundSpeedModel) -> Vec<(f64, f64, f64, f64)> {
+
+    let pts = ts_profile.points();
+    let speed = |i: usize| {
+        let p = phx_ts_profile_pressure_calc(ts_profile, pts[i].z, g, eos);
+        model.speed_calc(pts[i].t, p, pts[i].s, pts[i].z)
+    };
+
+    let mut c1 = speed(0);
+    let mut layers = Vec::with_capacity(pts.len() - 1);
+    for i in 1..pts.len() {
+        let c2 = speed(i);
+        let dz = pts[i].z - pts[i - 1].z;
+        let dc = c2 - c1;
+        let dt = if dc == 0.0 { dz / c1 } else { dz * (dc / c1).ln_1p() / dc };
+        layers.push((pts[i - 1].z, c1, dc / dz, dt));
+        c1 = c2;
+    }
+
+    layers
+}
+
+// Calculates the path, which sound traveled in vertical direction
+// between the water surface and the deepest point during a given time of flight,
+// see phx_try_vertical_sound_path_ts_profile
+// Sound speed is assumed to vary linearly with depth between the TS-profile points,
+// so the travel time through a layer has a closed form and no time stepping is needed:
+// t = ln(c2 / c1) * (z2 - z1) / (c2 - c1)
+// tof - time of flight, sec
+// g - gravity acceleration, m/s^2
+// tsProfile - vertical Temperature-Salinity profile at the given point
+// eos - equation of state to calculate water density
+// model - sound speed equation
+// Returns an error if the arguments are invalid instead of panicking
+pub fn phx_try_vertical_sound_path_exact_ts_profile(tof: f64, g: f64, ts_profile: &TsProfile, eos: EquationOfState, model: SoundSpeedModel) -> Result<f64, PhxError> {
+    phx_try_vertical_sound_path_exact_ts_profile_gravity_model(tof, GravityModel::Constant(g), ts_profile, eos, model)
+}
+
+// Calculates the vertical path of sound, see phx_try_vertical_sound_path_exact_ts_profile
+// g - gravity model of the water column
+pub fn phx_try_vertical_sound_path_exact_ts_profile_gravity_model(tof: f64, g: GravityModel, ts_profile: &TsProfile, eos: EquationOfState, model: SoundSpeedModel) -> Result<f64, PhxError> {
+
+    let layers = phx_ts_profile_sound_layers(ts_profile, g, eos, model);
+
+    let tof_max = layers.iter().map(|l| l.3).sum::<f64>();
+    if !(tof >= 0.0 && tof <= tof_max) {
+        return Err(PhxError::TimeOfFlightOutOfRange { value: tof, min: 0.0, max: tof_max });
+    }
+
+    // the layer, where the sound is after the time of flight
+    let mut tau = tof;
+    for &(z1, c1, k, dt) in layers.iter() {
+        if tau < dt {
+            // z = z1 + c1 * (exp(k * tau) - 1) / k
+            let kt = k * tau;
+            return Ok(z1 + if kt == 0.0 { c1 * tau } else { c1 * tau * kt.exp_m1() / kt });
+        }
+        tau -= dt;
+    }
+
+    Ok(ts_profile.max_depth())
+}
+
+// Calculates the vertical path of sound, see phx_try_vertical_sound_path_exact_ts_profile
+// tsProfile - as an array of (f64, f64, f64) ordered by depth
+// Panics if the arguments are invalid
+pub fn phx_vertical_sound_path_exact_ts_profile(tof: f64, g: f64, ts_profile: &[(f64, f64, f64)]) -> f64 {
+
+    match phx_try_vertical_sound_path_exact_ts_profile(tof, g, &phx_ts_profile_from_tuples(ts_profile), EquationOfState::Eos80, SoundSpeedModel::ChenMillero) {
+        Ok(h) => h,
+        Err(e) => panic!("{}", e),
+    }
+}
+
 // Calculated the freezing temperature of seawater (in °C) with specified pressure and salinity.
 // According to:
 // Algorithms for computation of fundamental properties of seawater. 
@@ -304,19 +858,18 @@ pub fn alpha_e_francois_garrison_calc(f: f64, t: f64, s: f64, h: f64, ph: f64) -
     let f2 = (8.17 * 10f64.powf(8.0 - 1990.0 / t_kel)) / (1.0 + 0.0018 * (s - 35.0));
     let mgso4 = (a2 * p2 * f2 * fsq) / (fsq + f2 * f2);
 
-    let a3: f64;
-    if t <= 20.0 {
-        a3 = 4.937E-4 - 2.59E-5 * t + 9.11E-7 * t * t - 1.5E-8 * t * t * t;
+    let a3 = if t <= 20.0 {
+        4.937E-4 - 2.59E-5 * t + 9.11E-7 * t * t - 1.5E-8 * t * t * t
     }
     else {
-        a3 = 3.964E-4 - 1.146E-5 * t + 1.45E-7 * t * t - 6.5E-10 * t * t * t;
-    }
+        3.964E-4 - 1.146E-5 * t + 1.45E-7 * t * t - 6.5E-10 * t * t * t
+    };
 
     let p3 = 1.0 - 3.83E-5 * h + 4.9E-10 * h * h;
     let h2o = a3 * p3 * fsq;
 
     // Total absorption
-    (boric + mgso4 + h2o)
+    boric + mgso4 + h2o
 }
 
 
@@ -525,36 +1078,39 @@ mod tests {
         }
     }
 
+    // Depth (m) by pressure (mBar) of the standard ocean (S = 35 PSU, T = 0 °C) at the latitudes (deg)
+    // according to
+    // Algorithms for computation of fundamental properties of seawater. 
+    // Unesco technical papers in marine science vol. 44, 1983, pp. 28
+    // https://darchive.mblwhoilibrary.org/bitstream/handle/1912/2470/059832eb.pdf
+    // (the depth at 10000 dbar and 60° is 9687.03 m: the columns of the table
+    // decrease by 12.8 m per row)
+    pub(crate) const REF_PRESSURE: [f64; 11] = [ 5E4, 1E5, 2E5, 3E5, 4E5, 5E5, 6E5, 7E5, 8E5, 9E5, 1E6 ];
+    pub(crate) const REF_LATS_DEG: [f64; 5] = [ 0.0, 30.0, 45.0, 60.0, 90.0 ];
+    pub(crate) const REF_DPT_LAT: [[f64; 11]; 5] = [ [ 496.65, 992.12, 1979.55, 2962.43, 3940.88, 4915.04, 5885.03, 6850.95, 7812.93, 8771.07, 9725.47 ],
+                                                     [ 496.00, 990.81, 1976.94, 2958.52, 3935.68, 4908.56, 5877.27, 6841.92, 7802.63, 8759.51, 9712.65 ],
+                                                     [ 495.34, 989.50, 1974.33, 2954.61, 3930.49, 4902.08, 5869.51, 6832.89, 7792.33, 8747.95, 9699.84 ],
+                                                     [ 494.69, 988.19, 1971.72, 2950.71, 3925.30, 4895.60, 5861.76, 6823.86, 7782.04, 8736.40, 9687.03 ],
+                                                     [ 494.03, 986.88, 1969.11, 2946.81, 3920.10, 4889.13, 5854.01, 6814.84, 7771.76, 8724.85, 9674.23 ]
+                                                   ];
+
     #[test]
     fn phx_pressure_by_depth_calc_test() {
 
-        // Reference values according to 
-        // Algorithms for computation of fundamental properties of seawater. 
-        // Unesco technical papers in marine science vol. 44, 1983, pp. 28
-        // https://darchive.mblwhoilibrary.org/bitstream/handle/1912/2470/059832eb.pdf
-
         let ref_salinity = 35.0;
         let ref_temperature = 0.0;
-        let ref_pressure: [f64; 11] = [ 5E4, 1E5, 2E5, 3E5, 4E5, 5E5, 6E5, 7E5, 8E5, 9E5, 1E6 ];
-        let ref_lats_deg: [f64; 5] = [ 0.0, 30.0, 45.0, 60.0, 90.0 ];
-        let ref_dpt_lat: [[f64; 11]; 5] = [ [ 496.65, 992.12, 1979.55, 2962.43, 3940.88, 4915.04, 5885.03, 6850.95, 7812.93, 8771.07, 9725.47 ],
-                                            [ 496.00, 990.81, 1976.94, 2958.52, 3935.68, 4908.56, 5877.27, 6841.92, 7802.63, 8759.51, 9712.65 ],
-                                            [ 495.34, 989.50, 1974.33, 2954.61, 3930.49, 4902.08, 5869.51, 6832.89, 7792.33, 8747.95, 9699.84 ],
-                                            [ 494.69, 988.19, 1971.72, 2950.71, 3925.30, 4895.60, 5861.76, 6823.86, 7782.04, 8736.40, 9687.84 ],
-                                            [ 494.03, 986.88, 1969.11, 2946.81, 3920.10, 4889.13, 5854.01, 6814.84, 7771.76, 8724.85, 9674.23 ]
-                                          ];
 
-        for l_idx in 0..ref_lats_deg.len() {
+        for l_idx in 0..REF_LATS_DEG.len() {
 
-            for p_idx in 0..ref_pressure.len() {
+            for p_idx in 0..REF_PRESSURE.len() {
 
-                let g = phx_gravity_constant_wgs84_calc(ref_lats_deg[l_idx].to_radians());
+                let g = phx_gravity_constant_wgs84_calc(REF_LATS_DEG[l_idx].to_radians());
                 // taking the water density in the midpoint to consider the compression of water
-                let rho = phx_water_density_calc(ref_temperature, ref_pressure[p_idx] / 2.0, ref_salinity);
-                let h_est = phx_depth_by_pressure_calc(ref_pressure[p_idx], 0.0, rho, g);
+                let rho = phx_water_density_calc(ref_temperature, REF_PRESSURE[p_idx] / 2.0, ref_salinity);
+                let h_est = phx_depth_by_pressure_calc(REF_PRESSURE[p_idx], 0.0, rho, g);
 
                 // calculated values should deviate less than 0.07% of reference values
-                assert_approx_eq!(h_est, ref_dpt_lat[l_idx][p_idx], ref_dpt_lat[l_idx][p_idx] * 0.0007);
+                assert_approx_eq!(h_est, REF_DPT_LAT[l_idx][p_idx], REF_DPT_LAT[l_idx][p_idx] * 0.0007);
             }
         }
     }
@@ -590,4 +1146,251 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn phx_try_ts_profile_errors_test() {
+
+        let tuples = [ (0.0, 20.0, 35.0), (100.0, 15.0, 35.0), (500.0, 5.0, 35.0) ];
+        let ts_profile = TsProfile::from_tuples(&tuples).unwrap();
+        let g = PHX_GRAVITY_ACC_MPS2;
+
+        assert_eq!(phx_try_depth_by_pressure_ts_profile(5000.0, PHX_ATM_PRESSURE_MBAR, g, &ts_profile, 0, EquationOfState::Eos80),
+                   Err(PhxError::InvalidStepCount { steps: 0 }));
+        assert_eq!(phx_try_vertical_sound_path_ts_profile(0.01, -1, g, &ts_profile, EquationOfState::Eos80, SoundSpeedModel::ChenMillero),
+                   Err(PhxError::InvalidStepCount { steps: -1 }));
+
+        match phx_try_depth_by_pressure_ts_profile(1.0E5, PHX_ATM_PRESSURE_MBAR, g, &ts_profile, 100, EquationOfState::Eos80) {
+            Err(PhxError::PressureOutOfRange { value, min, max }) => {
+                assert_approx_eq!(value, 1.0E5, 1E-9);
+                assert_approx_eq!(min, PHX_ATM_PRESSURE_MBAR, 1E-9);
+                assert!(max < value);
+            },
+            r => panic!("unexpected result {:?}", r),
+        }
+
+        // any pressure within the reported range gives a depth, including its upper end
+        match phx_try_depth_by_pressure_ts_profile(1.0E5, PHX_ATM_PRESSURE_MBAR, g, &ts_profile, 100, EquationOfState::Eos80) {
+            Err(PhxError::PressureOutOfRange { max, .. }) => {
+                for &n_p in [ 3, 7, 100, 1000 ].iter() {
+                    let h = phx_try_depth_by_pressure_ts_profile(max, PHX_ATM_PRESSURE_MBAR, g, &ts_profile, n_p, EquationOfState::Eos80).unwrap();
+                    // the range is estimated with the surface density, the water below is denser
+                    assert!(h > 495.0 && h <= 500.0);
+                }
+            },
+            r => panic!("unexpected result {:?}", r),
+        }
+
+        match phx_try_vertical_sound_path_ts_profile(1.0, 100, g, &ts_profile, EquationOfState::Eos80, SoundSpeedModel::ChenMillero) {
+            Err(PhxError::TimeOfFlightOutOfRange { value, max, .. }) => assert!(max < value),
+            r => panic!("unexpected result {:?}", r),
+        }
+
+        // sound speed grows with depth in isothermal water, so the deepest point is reached
+        // earlier than the surface sound speed predicts, any time of flight up to the limit gives a path
+        let isothermal = TsProfile::from_tuples(&[ (0.0, 10.0, 35.0), (500.0, 10.0, 35.0) ]).unwrap();
+        let tof_surface = 500.0 / phx_speed_of_sound_unesco_calc(10.0, PHX_ATM_PRESSURE_MBAR, 35.0);
+        let tof_max = match phx_try_vertical_sound_path_ts_profile(tof_surface, 1000, g, &isothermal, EquationOfState::Eos80, SoundSpeedModel::ChenMillero) {
+            Err(PhxError::TimeOfFlightOutOfRange { max, .. }) => max,
+            r => panic!("unexpected result {:?}", r),
+        };
+        assert!(tof_max < tof_surface);
+        match phx_try_vertical_sound_path_exact_ts_profile(tof_surface, g, &isothermal, EquationOfState::Eos80, SoundSpeedModel::ChenMillero) {
+            Err(PhxError::TimeOfFlightOutOfRange { max, .. }) => assert_approx_eq!(max, tof_max, 1E-12),
+            r => panic!("unexpected result {:?}", r),
+        }
+        let h = phx_try_vertical_sound_path_ts_profile(0.999 * tof_max, 1000, g, &isothermal, EquationOfState::Eos80, SoundSpeedModel::ChenMillero).unwrap();
+        assert!(h > 499.0 && h <= 500.0);
+        let h = phx_try_vertical_sound_path_ts_profile(tof_max, 1000, g, &isothermal, EquationOfState::Eos80, SoundSpeedModel::ChenMillero).unwrap();
+        assert!(h > 499.0 && h <= 500.0);
+        assert!(phx_try_vertical_sound_path_ts_profile(1.001 * tof_max, 1000, g, &isothermal, EquationOfState::Eos80, SoundSpeedModel::ChenMillero).is_err());
+
+        let pm = 2.0E4;
+        assert_eq!(phx_try_depth_by_pressure_ts_profile(pm, PHX_ATM_PRESSURE_MBAR, g, &ts_profile, 1000, EquationOfState::Eos80),
+                   Ok(phx_depth_by_pressure_ts_profile(pm, PHX_ATM_PRESSURE_MBAR, g, &tuples, 1000)));
+        assert_eq!(phx_try_vertical_sound_path_ts_profile(0.2, 1000, g, &ts_profile, EquationOfState::Eos80, SoundSpeedModel::ChenMillero),
+                   Ok(phx_vertical_sound_path_ts_profile(0.2, 1000, g, &tuples)));
+
+        // a profile starting below the surface is extrapolated to it
+        let below = [ (10.0, 20.0, 35.0), (100.0, 15.0, 35.0), (500.0, 5.0, 35.0) ];
+        let extrapolated = [ (0.0, 20.0, 35.0), (10.0, 20.0, 35.0), (100.0, 15.0, 35.0), (500.0, 5.0, 35.0) ];
+        assert_eq!(phx_depth_by_pressure_ts_profile(pm, PHX_ATM_PRESSURE_MBAR, g, &below, 1000),
+                   phx_depth_by_pressure_ts_profile(pm, PHX_ATM_PRESSURE_MBAR, g, &extrapolated, 1000));
+        assert_eq!(phx_vertical_sound_path_ts_profile(0.2, 1000, g, &below),
+                   phx_vertical_sound_path_ts_profile(0.2, 1000, g, &extrapolated));
+    }
+
+    #[test]
+    fn phx_vertical_sound_path_exact_ts_profile_test() {
+
+        let tuples = [ (0.0, 20.0, 35.0), (100.0, 15.0, 35.0), (500.0, 5.0, 35.0) ];
+        let ts_profile = TsProfile::from_tuples(&tuples).unwrap();
+        let g = PHX_GRAVITY_ACC_MPS2;
+
+        // the stepping integrator converges to the closed form
+        let h = phx_vertical_sound_path_exact_ts_profile(0.05, g, &tuples);
+        let err_coarse = (phx_vertical_sound_path_ts_profile(0.05, 100, g, &tuples) - h).abs();
+        let err_fine = (phx_vertical_sound_path_ts_profile(0.05, 100000, g, &tuples) - h).abs();
+        assert!(err_fine < err_coarse);
+        assert_approx_eq!(err_fine, 0.0, 0.02);
+
+        // a dense profile, where the sound speed is almost linear between the points
+        let dense: Vec<(f64, f64, f64)> = (0..=200).map(|i| {
+            let z = (i as f64) * 5.0;
+            (z, 4.0 + 16.0 * (-z / 200.0).exp(), 35.0)
+        }).collect();
+        for &tof in [ 0.05, 0.2, 0.5 ].iter() {
+            assert_approx_eq!(phx_vertical_sound_path_exact_ts_profile(tof, g, &dense),
+                              phx_vertical_sound_path_ts_profile(tof, 100000, g, &dense), 0.01);
+        }
+
+        // exactly n_t steps are made, so the error of the first order scheme falls with the step
+        let err = |n_t: i32| (phx_vertical_sound_path_ts_profile(0.2, n_t, g, &dense) - phx_vertical_sound_path_exact_ts_profile(0.2, g, &dense)).abs();
+        assert!(err(10000) < 0.2 * err(1000));
+
+        assert_approx_eq!(phx_vertical_sound_path_exact_ts_profile(0.0, g, &tuples), 0.0, 1E-12);
+
+        match phx_try_vertical_sound_path_exact_ts_profile(1.0, g, &ts_profile, EquationOfState::Eos80, SoundSpeedModel::ChenMillero) {
+            Err(PhxError::TimeOfFlightOutOfRange { value, max, .. }) => {
+                assert!(max < value);
+                // the deepest point is reached exactly at the maximal time of flight
+                assert_approx_eq!(phx_vertical_sound_path_exact_ts_profile(max, g, &tuples), 500.0, 1E-9);
+                assert_approx_eq!(phx_vertical_sound_path_exact_ts_profile(max * (1.0 - 1E-12), g, &tuples), 500.0, 1E-6);
+            },
+            r => panic!("unexpected result {:?}", r),
+        }
+    }
+
+    #[test]
+    fn phx_try_depth_by_pressure_adaptive_ts_profile_test() {
+
+        let tuples = [ (0.0, 20.0, 35.0), (100.0, 15.0, 35.0), (500.0, 5.0, 35.0), (2000.0, 2.5, 34.7) ];
+        let ts_profile = TsProfile::from_tuples(&tuples).unwrap();
+        let (g, p0, eos) = (PHX_GRAVITY_ACC_MPS2, PHX_ATM_PRESSURE_MBAR, EquationOfState::Eos80);
+
+        for &pm in [ 2.0E4, 1.0E5, 1.5E5 ].iter() {
+
+            let h_ref = phx_depth_by_pressure_ts_profile(pm, p0, g, &tuples, 1000000);
+
+            let coarse = phx_try_depth_by_pressure_adaptive_ts_profile(pm, p0, g, &ts_profile, DepthTolerance::Absolute(1E-2), eos).unwrap();
+            let fine = phx_try_depth_by_pressure_adaptive_ts_profile(pm, p0, g, &ts_profile, DepthTolerance::Absolute(1E-8), eos).unwrap();
+            assert!(coarse.error <= 1E-2 && fine.error <= 1E-8);
+            assert!(fine.evaluations > coarse.evaluations);
+            assert_approx_eq!(coarse.depth, fine.depth, 1E-2);
+            assert_approx_eq!(fine.depth, h_ref, 1E-3);
+
+            let relative = phx_try_depth_by_pressure_adaptive_ts_profile(pm, p0, g, &ts_profile, DepthTolerance::Relative(1E-9), eos).unwrap();
+            assert!(relative.error <= 1E-9 * relative.depth);
+            assert_approx_eq!(relative.depth, fine.depth, 1E-6);
+        }
+
+        let surface = phx_try_depth_by_pressure_adaptive_ts_profile(p0, p0, g, &ts_profile, DepthTolerance::Absolute(1E-3), eos).unwrap();
+        assert_eq!(surface, DepthEstimate { depth: 0.0, error: 0.0, evaluations: 0 });
+
+        assert_eq!(phx_try_depth_by_pressure_adaptive_ts_profile(2.0E4, p0, g, &ts_profile, DepthTolerance::Relative(0.0), eos),
+                   Err(PhxError::InvalidTolerance { value: 0.0 }));
+        match phx_try_depth_by_pressure_adaptive_ts_profile(1.0E6, p0, g, &ts_profile, DepthTolerance::Absolute(1E-3), eos) {
+            Err(PhxError::PressureOutOfRange { value, .. }) => assert_approx_eq!(value, 1.0E6, 1E-9),
+            r => panic!("unexpected result {:?}", r),
+        }
+    }
+
+    #[test]
+    fn phx_try_pressure_by_depth_ts_profile_test() {
+
+        let tuples = [ (0.0, 20.0, 35.0), (100.0, 15.0, 35.0), (500.0, 5.0, 35.0), (2000.0, 2.5, 34.7) ];
+        let ts_profile = TsProfile::from_tuples(&tuples).unwrap();
+        let (g, p0, eos) = (PHX_GRAVITY_ACC_MPS2, PHX_ATM_PRESSURE_MBAR, EquationOfState::Eos80);
+
+        assert_approx_eq!(phx_pressure_by_depth_ts_profile(0.0, p0, g, &tuples, 10), p0, 1E-9);
+
+        // the inverse of the depth by pressure
+        for &z in [ 10.0, 100.0, 750.0, 1900.0 ].iter() {
+            let p = phx_pressure_by_depth_ts_profile(z, p0, g, &tuples, 10000);
+            let h = phx_try_depth_by_pressure_adaptive_ts_profile(p, p0, g, &ts_profile, DepthTolerance::Absolute(1E-6), eos).unwrap();
+            assert_approx_eq!(h.depth, z, 0.05);
+            // denser than the surface water
+            assert!(p > phx_pressure_by_depth_calc(z, p0, eos.density_calc(20.0, p0, 35.0), g));
+        }
+
+        // homogeneous water, where both integrals use the same density
+        let uniform = TsProfile::from_tuples(&[ (0.0, 10.0, 35.0), (1000.0, 10.0, 35.0) ]).unwrap();
+        let p = phx_try_pressure_by_depth_ts_profile(900.0, p0, g, &uniform, 1000, eos).unwrap();
+        let h = phx_try_depth_by_pressure_adaptive_ts_profile(p, p0, g, &uniform, DepthTolerance::Absolute(1E-6), eos).unwrap();
+        assert_approx_eq!(h.depth, 900.0, 1E-4);
+
+        assert_eq!(phx_try_pressure_by_depth_ts_profile(2500.0, p0, g, &ts_profile, 100, eos),
+                   Err(PhxError::DepthOutOfRange { value: 2500.0, min: 0.0, max: 2000.0 }));
+        assert_eq!(phx_try_pressure_by_depth_ts_profile(100.0, p0, g, &ts_profile, 0, eos),
+                   Err(PhxError::InvalidStepCount { steps: 0 }));
+    }
+
+    #[test]
+    fn phx_ts_profile_gravity_model_test() {
+
+        let tuples = [ (0.0, 20.0, 35.0), (1000.0, 4.0, 34.8), (11000.0, 2.0, 34.7) ];
+        let ts_profile = TsProfile::from_tuples(&tuples).unwrap();
+        let (p0, eos) = (PHX_ATM_PRESSURE_MBAR, EquationOfState::Eos80);
+        let lat_rad = 11.3f64.to_radians();
+        let g0 = phx_gravity_constant_wgs84_calc(lat_rad);
+        let corrected = GravityModel::DepthCorrected { g0 };
+
+        // stronger gravity at depth: higher pressure at the same depth and smaller depth at the same pressure
+        let z = 10000.0;
+        let p_const = phx_try_pressure_by_depth_ts_profile(z, p0, g0, &ts_profile, 10000, eos).unwrap();
+        let p_corr = phx_try_pressure_by_depth_ts_profile_gravity_model(z, p0, corrected, &ts_profile, 10000, eos).unwrap();
+        assert!(p_corr > p_const);
+        // about 1/2 of the gravity change at the bottom
+        assert_approx_eq!(p_corr / p_const - 1.0, 0.5 * corrected.depth_gradient() * z / g0, 2E-4);
+
+        let h = phx_try_depth_by_pressure_adaptive_ts_profile_gravity_model(p_corr, p0, corrected, &ts_profile, DepthTolerance::Absolute(1E-6), eos).unwrap();
+        assert_approx_eq!(h.depth, z, 0.5);
+        let h_stepping = phx_try_depth_by_pressure_ts_profile_gravity_model(p_corr, p0, corrected, &ts_profile, 100000, eos).unwrap();
+        assert_approx_eq!(h_stepping, h.depth, 0.5);
+
+        // the same results with the Somigliana gravity at the ellipsoid
+        let somigliana = GravityModel::Somigliana { lat_rad, height: 0.0 };
+        assert_approx_eq!(phx_try_pressure_by_depth_ts_profile_gravity_model(z, p0, somigliana, &ts_profile, 10000, eos).unwrap(), p_corr, 1E-6);
+        let table = PressureDepthTable::try_new_gravity_model(p0, somigliana, &ts_profile, 11000, eos).unwrap();
+        assert_approx_eq!(table.try_pressure_calc(z).unwrap(), p_corr, 1E-2);
+
+        // constant gravity is the plain g versions
+        let model = SoundSpeedModel::ChenMillero;
+        assert_eq!(phx_try_vertical_sound_path_exact_ts_profile(1.0, g0, &ts_profile, eos, model),
+                   phx_try_vertical_sound_path_exact_ts_profile_gravity_model(1.0, GravityModel::Constant(g0), &ts_profile, eos, model));
+        assert_eq!(phx_try_vertical_sound_path_ts_profile(1.0, 1000, g0, &ts_profile, eos, model),
+                   phx_try_vertical_sound_path_ts_profile_gravity_model(1.0, 1000, GravityModel::Constant(g0), &ts_profile, eos, model));
+        assert!(phx_try_vertical_sound_path_ts_profile_gravity_model(1.0, 1000, corrected, &ts_profile, eos, model).is_ok());
+    }
+
+    #[test]
+    fn pressure_depth_table_test() {
+
+        let tuples = [ (0.0, 20.0, 35.0), (100.0, 15.0, 35.0), (500.0, 5.0, 35.0), (2000.0, 2.5, 34.7) ];
+        let ts_profile = TsProfile::from_tuples(&tuples).unwrap();
+        let (g, p0, eos) = (PHX_GRAVITY_ACC_MPS2, PHX_ATM_PRESSURE_MBAR, EquationOfState::Eos80);
+
+        let table = PressureDepthTable::try_new(p0, g, &ts_profile, 2000, eos).unwrap();
+        assert_approx_eq!(table.max_depth(), 2000.0, 1E-9);
+        assert!(table.points().iter().any(|pt| pt.0 == 100.0));
+
+        // simulated pressure sensor readings along a dive
+        for &z in [ 0.0, 3.3, 100.0, 427.5, 1999.9, 2000.0 ].iter() {
+            let p = table.try_pressure_calc(z).unwrap();
+            assert_approx_eq!(p, phx_try_pressure_by_depth_ts_profile(z, p0, g, &ts_profile, 20000, eos).unwrap(), 1E-2);
+            assert_approx_eq!(table.try_depth_calc(p).unwrap(), z, 1E-9);
+        }
+
+        assert_eq!(table.try_pressure_calc(-1.0), Err(PhxError::DepthOutOfRange { value: -1.0, min: 0.0, max: 2000.0 }));
+        match table.try_depth_calc(table.max_pressure() + 1.0) {
+            Err(PhxError::PressureOutOfRange { min, .. }) => assert_approx_eq!(min, p0, 1E-9),
+            r => panic!("unexpected result {:?}", r),
+        }
+        assert_eq!(PressureDepthTable::try_new(p0, g, &ts_profile, 0, eos), Err(PhxError::InvalidStepCount { steps: 0 }));
+    }
+
+    #[test]
+    #[should_panic]
+    fn phx_depth_by_pressure_ts_profile_panic_test() {
+        phx_depth_by_pressure_ts_profile(2.0E4, PHX_ATM_PRESSURE_MBAR, PHX_GRAVITY_ACC_MPS2, &[ (0.0, 20.0, 35.0) ], 100);
+    }
 }
@@ -0,0 +1,204 @@
+// Ambient noise of the ocean
+// Wenz, G. M. (1962). Acoustic ambient noise in the ocean: spectra and sources.
+// J. Acoust. Soc. Am., 34(12), 1936-1956
+// with the empirical approximations of the Wenz curves by:
+// Coates, R. F. W. Underwater Acoustic Systems, 1990
+// Stojanovic, M. (2007). On the relationship between capacity and distance in an underwater
+// acoustic communication channel. ACM SIGMOBILE Mob. Comput. Commun. Rev., 11(4), 34-43
+//
+// f - frequency, kHz
+// Noise spectral density levels are in dB re 1 µPa^2/Hz
+
+use crate::PhxError;
+
+const PHX_NOISE_BAND_GRID_SIZE: usize = 257; // number of frequencies for the band integration
+
+// Knudsen curves: noise level at 1 kHz for sea states 0..6, dB re 1 µPa^2/Hz
+// Urick, R. J. Principles of Underwater Sound, 3rd ed., 1983, Fig. 7.5
+const PHX_KNUDSEN_NL_1KHZ: [f64; 7] = [ 44.5, 55.0, 61.5, 64.5, 66.5, 68.5, 70.0 ];
+
+// Calculates the turbulence noise spectral density level, dB re 1 µPa^2/Hz
+// f - frequency, kHz
+pub fn phx_noise_turbulence_calc(f: f64) -> f64 {
+    17.0 - 30.0 * f.log10()
+}
+
+// Calculates the distant shipping noise spectral density level, dB re 1 µPa^2/Hz
+// f - frequency, kHz
+// shipping - shipping activity factor, 0..1
+pub fn phx_noise_shipping_calc(f: f64, shipping: f64) -> f64 {
+    40.0 + 20.0 * (shipping - 0.5) + 26.0 * f.log10() - 60.0 * (f + 0.03).log10()
+}
+
+// Calculates the wind-driven surface noise spectral density level, dB re 1 µPa^2/Hz
+// f - frequency, kHz
+// w - wind speed, m/s
+pub fn phx_noise_wind_calc(f: f64, w: f64) -> f64 {
+    50.0 + 7.5 * w.max(0.0).sqrt() + 20.0 * f.log10() - 40.0 * (f + 0.4).log10()
+}
+
+// Calculates the surface noise spectral density level by the Knudsen curves, dB re 1 µPa^2/Hz
+// the level decreases by 5 dB per octave from its value at 1 kHz
+// f - frequency, kHz
+// sea_state - sea state, 0..6, fractional values are interpolated
+pub fn phx_noise_knudsen_calc(f: f64, sea_state: f64) -> f64 {
+
+    let ss = sea_state.clamp(0.0, 6.0);
+    let idx = (ss.floor() as usize).min(5);
+    let nl_1khz = PHX_KNUDSEN_NL_1KHZ[idx] + (ss - idx as f64) * (PHX_KNUDSEN_NL_1KHZ[idx + 1] - PHX_KNUDSEN_NL_1KHZ[idx]);
+
+    nl_1khz - 5.0 * f.log2()
+}
+
+// Calculates the thermal noise spectral density level, dB re 1 µPa^2/Hz
+// f - frequency, kHz
+pub fn phx_noise_thermal_calc(f: f64) -> f64 {
+    -15.0 + 20.0 * f.log10()
+}
+
+// Calculates the rain noise spectral density level, dB re 1 µPa^2/Hz
+// The level at 5 kHz follows the acoustic rain gauge relation of
+// Ma, B. B., Nystuen, J. A. (2005). Passive acoustic detection and measurement of rainfall at sea.
+// J. Atmos. Oceanic Technol., 22, 1225-1248
+// with a slope of -5 dB/decade, the approximation is valid for 2..20 kHz
+// f - frequency, kHz
+// rain_rate - rain rate, mm/h
+pub fn phx_noise_rain_calc(f: f64, rain_rate: f64) -> f64 {
+    42.4 + 15.4 * rain_rate.log10() - 5.0 * (f / 5.0).log10()
+}
+
+/// Source of the surface agitation noise
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SurfaceNoise {
+    /// wind-driven noise by the wind speed, m/s
+    Wind { speed: f64 },
+    /// Knudsen curves by the sea state, 0..6
+    SeaState { state: f64 },
+}
+
+/// Parametric ambient noise model, the sum of turbulence, shipping,
+/// surface agitation, thermal and rain noise
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoiseModel {
+    /// shipping activity factor, 0..1
+    pub shipping: f64,
+    /// surface agitation noise
+    pub surface: SurfaceNoise,
+    /// rain rate, mm/h (None - no rain)
+    pub rain_rate: Option<f64>,
+}
+
+impl Default for NoiseModel {
+    fn default() -> NoiseModel {
+        NoiseModel { shipping: 0.5, surface: SurfaceNoise::Wind { speed: 0.0 }, rain_rate: None }
+    }
+}
+
+impl NoiseModel {
+
+    /// Calculates the total noise spectral density level, dB re 1 µPa^2/Hz
+    /// f - frequency, kHz
+    pub fn psd_calc(&self, f: f64) -> f64 {
+
+        let surface = match self.surface {
+            SurfaceNoise::Wind { speed } => phx_noise_wind_calc(f, speed),
+            SurfaceNoise::SeaState { state } => phx_noise_knudsen_calc(f, state),
+        };
+
+        let mut p = [ phx_noise_turbulence_calc(f), phx_noise_shipping_calc(f, self.shipping), surface, phx_noise_thermal_calc(f) ]
+            .iter().map(|&nl| 10f64.powf(nl / 10.0)).sum::<f64>();
+
+        if let Some(rain_rate) = self.rain_rate {
+            if rain_rate > 0.0 {
+                p += 10f64.powf(phx_noise_rain_calc(f, rain_rate) / 10.0);
+            }
+        }
+
+        10.0 * p.log10()
+    }
+
+    /// Calculates the noise level in a frequency band, dB re 1 µPa
+    /// f_low, f_high - lower and upper frequencies of the band, kHz
+    pub fn try_band_level_calc(&self, f_low: f64, f_high: f64) -> Result<f64, PhxError> {
+
+        if !(f_low > 0.0 && f_high > f_low && f_high.is_finite()) {
+            return Err(PhxError::InvalidBand { f_low, f_high });
+        }
+
+        // trapezoidal integration over logarithmically spaced frequencies
+        let n = PHX_NOISE_BAND_GRID_SIZE;
+        let k = (f_high / f_low).powf(1.0 / ((n - 1) as f64));
+        let mut f1 = f_low;
+        let mut p1 = 10f64.powf(self.psd_calc(f1) / 10.0);
+        let mut p = 0.0;
+
+        for _ in 1..n {
+            let f2 = f1 * k;
+            let p2 = 10f64.powf(self.psd_calc(f2) / 10.0);
+            // kHz to Hz
+            p += 0.5 * (p1 + p2) * (f2 - f1) * 1000.0;
+            f1 = f2;
+            p1 = p2;
+        }
+
+        Ok(10.0 * p.log10())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    #[test]
+    fn phx_noise_components_test() {
+
+        assert_approx_eq!(phx_noise_turbulence_calc(1.0), 17.0, 1E-9);
+        assert_approx_eq!(phx_noise_thermal_calc(100.0), 25.0, 1E-9);
+        assert_approx_eq!(phx_noise_wind_calc(10.0, 0.0), 70.0 - 40.0 * 10.4f64.log10(), 1E-9);
+        assert_approx_eq!(phx_noise_shipping_calc(10.0, 1.0) - phx_noise_shipping_calc(10.0, 0.0), 20.0, 1E-9);
+
+        // Knudsen curves: -5 dB per octave, interpolation between sea states
+        assert_approx_eq!(phx_noise_knudsen_calc(1.0, 3.0), 64.5, 1E-9);
+        assert_approx_eq!(phx_noise_knudsen_calc(2.0, 3.0), 59.5, 1E-9);
+        assert_approx_eq!(phx_noise_knudsen_calc(1.0, 2.5), 63.0, 1E-9);
+        assert_approx_eq!(phx_noise_knudsen_calc(1.0, 10.0), 70.0, 1E-9);
+
+        assert_approx_eq!(phx_noise_rain_calc(5.0, 10.0), 57.8, 1E-9);
+    }
+
+    #[test]
+    fn noise_model_test() {
+
+        let calm = NoiseModel::default();
+        let windy = NoiseModel { surface: SurfaceNoise::Wind { speed: 10.0 }, ..calm };
+        let rainy = NoiseModel { rain_rate: Some(20.0), ..windy };
+
+        // the total is not less than every component
+        for &f in [ 0.01, 0.1, 1.0, 10.0, 100.0, 1000.0 ].iter() {
+            let nl = calm.psd_calc(f);
+            assert!(nl >= phx_noise_turbulence_calc(f) && nl >= phx_noise_shipping_calc(f, 0.5));
+            assert!(nl >= phx_noise_wind_calc(f, 0.0) && nl >= phx_noise_thermal_calc(f));
+            assert!(windy.psd_calc(f) > nl);
+            assert!(rainy.psd_calc(f) > windy.psd_calc(f));
+        }
+
+        // thermal noise dominates at high frequencies
+        assert_approx_eq!(calm.psd_calc(1000.0), phx_noise_thermal_calc(1000.0), 0.5);
+
+        // sea state 3 is close to 10 m/s wind at 1..10 kHz
+        let ss3 = NoiseModel { surface: SurfaceNoise::SeaState { state: 3.0 }, ..calm };
+        assert_approx_eq!(ss3.psd_calc(5.0), windy.psd_calc(5.0), 6.0);
+
+        // band level of an almost flat narrow band: NL + 10 log10(B)
+        assert_approx_eq!(windy.try_band_level_calc(9.9, 10.1).unwrap(), windy.psd_calc(10.0) + 10.0 * 200.0f64.log10(), 0.05);
+
+        // band levels add up
+        let p1 = 10f64.powf(windy.try_band_level_calc(5.0, 10.0).unwrap() / 10.0);
+        let p2 = 10f64.powf(windy.try_band_level_calc(10.0, 20.0).unwrap() / 10.0);
+        assert_approx_eq!(windy.try_band_level_calc(5.0, 20.0).unwrap(), 10.0 * (p1 + p2).log10(), 1E-3);
+
+        assert_eq!(windy.try_band_level_calc(10.0, 10.0), Err(PhxError::InvalidBand { f_low: 10.0, f_high: 10.0 }));
+    }
+}
@@ -0,0 +1,236 @@
+use std::convert::TryFrom;
+
+use crate::{phx_linterp, PhxError};
+
+/// A point of a vertical Temperature-Salinity profile
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TsPoint {
+    /// vertical coordinate, m (positive, 0 - water surface)
+    pub z: f64,
+    /// temperature, °C
+    pub t: f64,
+    /// salinity, PSU
+    pub s: f64,
+}
+
+impl TsPoint {
+    pub fn new(z: f64, t: f64, s: f64) -> TsPoint {
+        TsPoint { z, t, s }
+    }
+}
+
+impl From<(f64, f64, f64)> for TsPoint {
+    fn from(p: (f64, f64, f64)) -> TsPoint {
+        TsPoint::new(p.0, p.1, p.2)
+    }
+}
+
+impl From<TsPoint> for (f64, f64, f64) {
+    fn from(p: TsPoint) -> (f64, f64, f64) {
+        (p.z, p.t, p.s)
+    }
+}
+
+/// Vertical Temperature-Salinity profile, which is guaranteed to
+/// contain at least two points with finite values and strictly increasing
+/// depths, the first one being at the water surface (z = 0)
+///
+/// If the shallowest given point is below the surface, its temperature and salinity
+/// are extrapolated to the surface by an extra point at z = 0, as the TS-profile
+/// integrators always did with such profiles. Points above the surface (z < 0) are rejected.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TsProfile {
+    points: Vec<TsPoint>,
+}
+
+impl TsProfile {
+
+    /// Builds a profile from points given in any order:
+    /// points are sorted by depth, points with equal depths are merged
+    /// into one with averaged temperature and salinity
+    pub fn new(points: Vec<TsPoint>) -> Result<TsProfile, PhxError> {
+
+        TsProfile::check_finite(&points)?;
+
+        let mut points = points;
+        points.sort_by(|a, b| a.z.partial_cmp(&b.z).unwrap());
+
+        TsProfile::build(points)
+    }
+
+    /// Builds a profile from points ordered by depth, out-of-order points
+    /// are rejected, points with equal depths are merged
+    pub fn from_ordered(points: Vec<TsPoint>) -> Result<TsProfile, PhxError> {
+
+        TsProfile::check_finite(&points)?;
+
+        for idx in 1..points.len() {
+            if points[idx].z < points[idx - 1].z {
+                return Err(PhxError::NonMonotonicDepth { index: idx });
+            }
+        }
+
+        TsProfile::build(points)
+    }
+
+    /// Builds a profile from (z, t, s) tuples given in any order, see TsProfile::new
+    pub fn from_tuples(points: &[(f64, f64, f64)]) -> Result<TsProfile, PhxError> {
+        TsProfile::new(points.iter().map(|&p| TsPoint::from(p)).collect())
+    }
+
+    /// Builds a profile from an iterator over points given in any order, see TsProfile::new
+    pub fn try_from_iter<I>(points: I) -> Result<TsProfile, PhxError>
+        where I: IntoIterator, I::Item: Into<TsPoint> {
+        TsProfile::new(points.into_iter().map(|p| p.into()).collect())
+    }
+
+    fn check_finite(points: &[TsPoint]) -> Result<(), PhxError> {
+
+        match points.iter().position(|p| !(p.z.is_finite() && p.t.is_finite() && p.s.is_finite())) {
+            Some(index) => Err(PhxError::NonFiniteValue { index }),
+            None => Ok(()),
+        }
+    }
+
+    // points are expected to be finite and sorted by depth
+    fn build(points: Vec<TsPoint>) -> Result<TsProfile, PhxError> {
+
+        let mut merged: Vec<TsPoint> = Vec::with_capacity(points.len());
+        let mut n_merged = 1.0;
+
+        for p in points {
+            match merged.last_mut() {
+                Some(last) if last.z == p.z => {
+                    n_merged += 1.0;
+                    last.t += (p.t - last.t) / n_merged;
+                    last.s += (p.s - last.s) / n_merged;
+                },
+                _ => {
+                    n_merged = 1.0;
+                    merged.push(p);
+                },
+            }
+        }
+
+        if merged.len() < 2 {
+            return Err(PhxError::TooFewPoints { count: merged.len() });
+        }
+
+        let first = merged[0];
+        if first.z < 0.0 {
+            return Err(PhxError::NotAtSurface { z: first.z });
+        }
+        if first.z > 0.0 {
+            merged.insert(0, TsPoint::new(0.0, first.t, first.s));
+        }
+
+        Ok(TsProfile { points: merged })
+    }
+
+    pub fn points(&self) -> &[TsPoint] {
+        &self.points
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Always false, a valid profile contains at least two points
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Depth of the deepest point of the profile, m
+    pub fn max_depth(&self) -> f64 {
+        self.points[self.points.len() - 1].z
+    }
+
+    /// Temperature and salinity at the given depth, linearly interpolated
+    /// between the points of the profile and constant beyond them
+    pub fn interpolate(&self, z: f64) -> TsPoint {
+
+        let z = z.clamp(0.0, self.max_depth());
+        let idx = self.points.iter().take(self.points.len() - 1).rposition(|p| p.z <= z).unwrap_or(0);
+        let (p1, p2) = (self.points[idx], self.points[idx + 1]);
+
+        TsPoint::new(z, phx_linterp(p1.z, p1.t, p2.z, p2.t, z), phx_linterp(p1.z, p1.s, p2.z, p2.s, z))
+    }
+}
+
+impl TryFrom<Vec<TsPoint>> for TsProfile {
+    type Error = PhxError;
+
+    fn try_from(points: Vec<TsPoint>) -> Result<TsProfile, PhxError> {
+        TsProfile::new(points)
+    }
+}
+
+impl TryFrom<&[TsPoint]> for TsProfile {
+    type Error = PhxError;
+
+    fn try_from(points: &[TsPoint]) -> Result<TsProfile, PhxError> {
+        TsProfile::new(points.to_vec())
+    }
+}
+
+impl TryFrom<Vec<(f64, f64, f64)>> for TsProfile {
+    type Error = PhxError;
+
+    fn try_from(points: Vec<(f64, f64, f64)>) -> Result<TsProfile, PhxError> {
+        TsProfile::from_tuples(&points)
+    }
+}
+
+impl TryFrom<&[(f64, f64, f64)]> for TsProfile {
+    type Error = PhxError;
+
+    fn try_from(points: &[(f64, f64, f64)]) -> Result<TsProfile, PhxError> {
+        TsProfile::from_tuples(points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    #[test]
+    fn ts_profile_construction_test() {
+
+        let profile = TsProfile::from_tuples(&[ (100.0, 15.0, 35.0), (0.0, 20.0, 34.0), (50.0, 17.0, 34.5), (50.0, 18.0, 34.7) ]).unwrap();
+
+        assert_eq!(profile.len(), 3);
+        assert_eq!(profile.points()[0], TsPoint::new(0.0, 20.0, 34.0));
+        assert_eq!(profile.points()[2], TsPoint::new(100.0, 15.0, 35.0));
+        assert_approx_eq!(profile.points()[1].t, 17.5, 1E-12);
+        assert_approx_eq!(profile.points()[1].s, 34.6, 1E-12);
+        assert_approx_eq!(profile.max_depth(), 100.0, 1E-12);
+        assert_eq!(profile.interpolate(75.0), TsPoint::new(75.0, 16.25, 34.8));
+        assert_eq!(profile.interpolate(100.0), TsPoint::new(100.0, 15.0, 35.0));
+        assert_eq!(profile.interpolate(150.0), TsPoint::new(100.0, 15.0, 35.0));
+
+        let from_iter = TsProfile::try_from_iter(vec![ (0.0, 20.0, 34.0), (50.0, 17.5, 34.6), (100.0, 15.0, 35.0) ]).unwrap();
+        assert_eq!(from_iter.len(), profile.len());
+        assert_eq!(TsProfile::try_from(vec![ TsPoint::new(0.0, 20.0, 34.0), TsPoint::new(10.0, 19.0, 34.0) ]).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn ts_profile_validation_test() {
+
+        assert_eq!(TsProfile::from_tuples(&[ (0.0, 20.0, 35.0) ]), Err(PhxError::TooFewPoints { count: 1 }));
+        assert_eq!(TsProfile::from_tuples(&[ (0.0, 20.0, 35.0), (0.0, 19.0, 35.0) ]), Err(PhxError::TooFewPoints { count: 1 }));
+        assert_eq!(TsProfile::from_tuples(&[ (0.0, 20.0, 35.0), (10.0, f64::NAN, 35.0) ]), Err(PhxError::NonFiniteValue { index: 1 }));
+        assert_eq!(TsProfile::from_tuples(&[ (-5.0, 20.0, 35.0), (10.0, 19.0, 35.0) ]), Err(PhxError::NotAtSurface { z: -5.0 }));
+        assert_eq!(TsProfile::from_tuples(&[]), Err(PhxError::TooFewPoints { count: 0 }));
+
+        // the shallowest point is extrapolated to the surface
+        let below = TsProfile::from_tuples(&[ (5.0, 20.0, 35.0), (10.0, 19.0, 34.0) ]).unwrap();
+        assert_eq!(below.points(), &[ TsPoint::new(0.0, 20.0, 35.0), TsPoint::new(5.0, 20.0, 35.0), TsPoint::new(10.0, 19.0, 34.0) ]);
+        assert_eq!(TsProfile::from_tuples(&[ (5.0, 20.0, 35.0) ]), Err(PhxError::TooFewPoints { count: 1 }));
+
+        let unordered = vec![ TsPoint::new(0.0, 20.0, 35.0), TsPoint::new(100.0, 15.0, 35.0), TsPoint::new(50.0, 17.0, 35.0) ];
+        assert_eq!(TsProfile::from_ordered(unordered.clone()), Err(PhxError::NonMonotonicDepth { index: 2 }));
+        assert!(TsProfile::new(unordered).is_ok());
+    }
+}
@@ -0,0 +1,182 @@
+// The Practical Salinity Scale 1978 (PSS-78)
+// Unesco technical papers in marine science vol. 44, 1983, pp. 6-12
+// https://darchive.mblwhoilibrary.org/bitstream/handle/1912/2470/059832eb.pdf
+// with the low salinity extension of:
+// Hill, K. D., Dauphinee, T. M., Woods, D. J. (1986). The extension of the Practical
+// Salinity Scale 1978 to low salinities. IEEE J. Oceanic Eng., 11(1), 109-112
+
+use crate::phx_water_density_calc;
+
+pub const PHX_PSS78_C3515_MSCM: f64 = 42.914; // Conductivity of seawater with S = 35 PSU, T = 15 °C, p = 0, mS/cm
+
+// Salinity by the square root of Rt and t - 15
+fn phx_pss78_sal(xr: f64, xt: f64) -> f64 {
+    ((((2.7081 * xr - 7.0261) * xr + 14.0941) * xr + 25.3851) * xr - 0.1692) * xr + 0.0080 +
+    (xt / (1.0 + 0.0162 * xt)) * (((((-0.0144 * xr + 0.0636) * xr - 0.0375) * xr - 0.0066) * xr - 0.0056) * xr + 0.0005)
+}
+
+// Derivative of phx_pss78_sal by xr
+fn phx_pss78_dsal(xr: f64, xt: f64) -> f64 {
+    (((13.5405 * xr - 28.1044) * xr + 42.2823) * xr + 50.7702) * xr - 0.1692 +
+    (xt / (1.0 + 0.0162 * xt)) * ((((-0.0720 * xr + 0.2544) * xr - 0.1125) * xr - 0.0132) * xr - 0.0056)
+}
+
+// Ratio of conductivity of standard seawater (S = 35) at temperature t to its conductivity at 15 °C
+fn phx_pss78_rt35(t: f64) -> f64 {
+    (((1.0031E-9 * t - 6.9698E-7) * t + 1.104259E-4) * t + 2.00564E-2) * t + 0.6766097
+}
+
+// Pressure correction coefficients a(t), b(t), c(p), p in dbar
+fn phx_pss78_abc(t: f64, p: f64) -> (f64, f64, f64) {
+    (-3.107E-3 * t + 0.4215,
+     (4.464E-4 * t + 3.426E-2) * t + 1.0,
+     ((3.989E-15 * p - 6.370E-10) * p + 2.070E-5) * p)
+}
+
+// Salinity by Rt with the low salinity extension
+fn phx_pss78_salinity_by_rt(rt: f64, t: f64) -> f64 {
+
+    let dt = t - 15.0;
+    let s = phx_pss78_sal(rt.abs().sqrt(), dt);
+
+    if s < 2.0 {
+        let x = 400.0 * rt;
+        let y = 100.0 * rt;
+        let yr = y.abs().sqrt();
+        s - 0.008 / (1.0 + 1.5 * x + x * x) - 0.0005 * (dt / (1.0 + 0.0162 * dt)) / (1.0 + yr + y * yr)
+    } else {
+        s
+    }
+}
+
+// Calculates the conductivity ratio R = C(S, t, p) / C(35, 15, 0)
+// c - conductivity, mS/cm
+pub fn phx_conductivity_ratio_calc(c: f64) -> f64 {
+    c / PHX_PSS78_C3515_MSCM
+}
+
+// Calculates practical salinity (PSU) by the conductivity ratio
+// r - conductivity ratio R = C(S, t, p) / C(35, 15, 0)
+// t - temperature, °C
+// p - pressure, mBar
+pub fn phx_salinity_pss78_calc(r: f64, t: f64, p: f64) -> f64 {
+
+    let (a, b, c) = phx_pss78_abc(t, p / 100.0);
+    let rp = 1.0 + c / (b + a * r);
+    let rt = r / (rp * phx_pss78_rt35(t));
+
+    phx_pss78_salinity_by_rt(rt, t)
+}
+
+// Calculates practical salinity (PSU) by the measured conductivity
+// c - conductivity, mS/cm
+// t - temperature, °C
+// p - pressure, mBar
+pub fn phx_salinity_by_conductivity_calc(c: f64, t: f64, p: f64) -> f64 {
+    phx_salinity_pss78_calc(phx_conductivity_ratio_calc(c), t, p)
+}
+
+// Calculates the conductivity ratio R = C(S, t, p) / C(35, 15, 0), inverse of phx_salinity_pss78_calc
+// s - salinity, PSU
+// t - temperature, °C
+// p - pressure, mBar
+pub fn phx_conductivity_ratio_pss78_calc(s: f64, t: f64, p: f64) -> f64 {
+
+    let dt = t - 15.0;
+
+    // Newton iterations for the square root of Rt, safeguarded by bisection
+    // as the low salinity extension has a tiny discontinuity at S = 2
+    let mut xr = (s.abs() / 35.0).sqrt();
+    let mut xr_min = 0.0;
+    let mut xr_max = 2.0;
+    for _ in 0..100 {
+        let ds = s - phx_pss78_salinity_by_rt(xr * xr, t);
+        if ds.abs() < 1E-10 || xr_max - xr_min < 1E-15 {
+            break;
+        }
+
+        if ds > 0.0 {
+            xr_min = xr;
+        } else {
+            xr_max = xr;
+        }
+
+        let xr_next = xr + ds / phx_pss78_dsal(xr, dt);
+        xr = if xr_next > xr_min && xr_next < xr_max { xr_next } else { 0.5 * (xr_min + xr_max) };
+    }
+
+    // solving the pressure correction for R
+    let rtt = phx_pss78_rt35(t) * xr * xr;
+    let (a, b, c) = phx_pss78_abc(t, p / 100.0);
+    let c = rtt * (c + b);
+    let b = b - rtt * a;
+
+    0.5 * ((b * b + 4.0 * a * c).abs().sqrt() - b) / a
+}
+
+// Calculates conductivity of seawater, mS/cm
+// s - salinity, PSU
+// t - temperature, °C
+// p - pressure, mBar
+pub fn phx_conductivity_by_salinity_calc(s: f64, t: f64, p: f64) -> f64 {
+    phx_conductivity_ratio_pss78_calc(s, t, p) * PHX_PSS78_C3515_MSCM
+}
+
+// Calculates in situ density of water (kg/m^3) by the measured conductivity
+// t - temperature, °C
+// p - pressure, mBar
+// c - conductivity, mS/cm
+pub fn phx_water_density_by_conductivity_calc(t: f64, p: f64, c: f64) -> f64 {
+    phx_water_density_calc(t, p, phx_salinity_by_conductivity_calc(c, t, p))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    #[test]
+    fn phx_salinity_pss78_calc_test() {
+
+        // Reference values according to
+        // Algorithms for computation of fundamental properties of seawater.
+        // Unesco technical papers in marine science vol. 44, 1983, pp. 9-11
+
+        assert_approx_eq!(phx_salinity_pss78_calc(1.888091, 40.0, 1E6), 40.0, 1E-5);
+        assert_approx_eq!(phx_salinity_pss78_calc(1.0, 15.0, 0.0), 35.0, 1E-6);
+        assert_approx_eq!(phx_salinity_pss78_calc(1.2, 20.0, 2E5), 37.245628, 1E-6);
+        assert_approx_eq!(phx_salinity_pss78_calc(0.65, 5.0, 1.5E5), 27.995347, 1E-6);
+
+        assert_approx_eq!(phx_conductivity_ratio_pss78_calc(40.0, 40.0, 1E6), 1.888091, 1E-6);
+        assert_approx_eq!(phx_salinity_by_conductivity_calc(PHX_PSS78_C3515_MSCM, 15.0, 0.0), 35.0, 1E-6);
+        assert_approx_eq!(phx_water_density_by_conductivity_calc(15.0, 0.0, PHX_PSS78_C3515_MSCM), phx_water_density_calc(15.0, 0.0, 35.0), 1E-6);
+
+        let ref_s: [f64; 6] = [ 0.5, 1.9, 10.0, 25.0, 35.0, 42.0 ];
+        let ref_t: [f64; 4] = [ 0.0, 10.0, 20.0, 30.0 ];
+        let ref_p: [f64; 3] = [ 0.0, 1E5, 5E5 ];
+
+        for &s in ref_s.iter() {
+            for &t in ref_t.iter() {
+                for &p in ref_p.iter() {
+                    let c = phx_conductivity_by_salinity_calc(s, t, p);
+                    assert_approx_eq!(phx_salinity_by_conductivity_calc(c, t, p), s, 1E-8);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn phx_salinity_pss78_low_salinity_test() {
+
+        // Hill et al. (1986) extension goes to zero with conductivity
+        // and joins PSS-78 at S = 2
+        for &t in [ 0.0, 5.0, 15.0, 25.0 ].iter() {
+            assert_approx_eq!(phx_salinity_pss78_calc(0.0, t, 0.0), 0.0, 1E-9);
+
+            assert_approx_eq!(phx_salinity_pss78_calc(phx_conductivity_ratio_pss78_calc(2.0, t, 0.0), t, 0.0), 2.0, 1E-4);
+        }
+
+        assert!(phx_salinity_pss78_calc(1E-3, 15.0, 0.0) > 0.0);
+    }
+}
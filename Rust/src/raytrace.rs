@@ -0,0 +1,610 @@
+// 2D ray tracing through a range-independent sound speed profile of constant-gradient layers,
+// where rays are arcs of circles and are traced in closed form, see e.g.
+// Jensen, F. B. et al. Computational Ocean Acoustics, 2011, ch. 3
+//
+// z - depth, m (positive, 0 - water surface)
+// r - horizontal range, m
+// theta - grazing angle, rad (positive - downwards)
+// The deepest point of the profile is a flat reflecting bottom
+
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use crate::phx_ts_profile_pressure_calc;
+use crate::{EquationOfState, GravityModel, PhxError, SoundSpeedModel, TsProfile};
+
+const PHX_RAY_MAX_EVENTS: usize = 100000; // maximal number of points of a ray
+
+/// Sound speed profile as a set of layers with constant sound speed gradients
+#[derive(Debug, Clone, PartialEq)]
+pub struct SoundSpeedProfile {
+    // (z, c) with strictly increasing depths, the first one at the surface
+    points: Vec<(f64, f64)>,
+}
+
+impl SoundSpeedProfile {
+
+    /// Builds a profile from (z, c) points ordered by depth, z in m, c in m/s
+    pub fn new(points: Vec<(f64, f64)>) -> Result<SoundSpeedProfile, PhxError> {
+
+        if points.len() < 2 {
+            return Err(PhxError::TooFewPoints { count: points.len() });
+        }
+
+        for (index, &(z, c)) in points.iter().enumerate() {
+            if !z.is_finite() {
+                return Err(PhxError::NonFiniteValue { index });
+            }
+            if !(c.is_finite() && c > 0.0) {
+                return Err(PhxError::InvalidSoundSpeed { index });
+            }
+            if index > 0 && z <= points[index - 1].0 {
+                return Err(PhxError::NonMonotonicDepth { index });
+            }
+        }
+
+        if points[0].0 != 0.0 {
+            return Err(PhxError::NotAtSurface { z: points[0].0 });
+        }
+
+        Ok(SoundSpeedProfile { points })
+    }
+
+    /// Builds a profile from a TS profile, pressure is obtained from depth
+    /// the same way as in phx_try_vertical_sound_path_ts_profile
+    /// ts_profile - vertical Temperature-Salinity profile
+    /// g - gravity acceleration, m/s^2
+    /// eos - equation of state to calculate water density
+    /// model - sound speed equation
+    pub fn from_ts_profile(ts_profile: &TsProfile, g: f64, eos: EquationOfState, model: SoundSpeedModel) -> SoundSpeedProfile {
+        SoundSpeedProfile::from_ts_profile_gravity_model(ts_profile, GravityModel::Constant(g), eos, model)
+    }
+
+    /// Builds a profile from a TS profile with the given gravity model, see SoundSpeedProfile::from_ts_profile
+    /// g - gravity model of the water column
+    pub fn from_ts_profile_gravity_model(ts_profile: &TsProfile, g: GravityModel, eos: EquationOfState, model: SoundSpeedModel) -> SoundSpeedProfile {
+
+        SoundSpeedProfile {
+            points: ts_profile.points().iter().map(|p| {
+                let pressure = phx_ts_profile_pressure_calc(ts_profile, p.z, g, eos);
+                (p.z, model.speed_calc(p.t, pressure, p.s, p.z))
+            }).collect(),
+        }
+    }
+
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+
+    /// Depth of the deepest point of the profile (the bottom), m
+    pub fn max_depth(&self) -> f64 {
+        self.points[self.points.len() - 1].0
+    }
+
+    /// Sound speed at the given depth, m/s, constant beyond the profile
+    pub fn speed_at(&self, z: f64) -> f64 {
+
+        let idx = self.layer_index(z, true);
+        let (z1, c1) = self.points[idx];
+        let z = z.clamp(0.0, self.max_depth());
+
+        c1 + self.gradient(idx) * (z - z1)
+    }
+
+    /// Sound speed gradient of the layer with the given index, 1/s
+    pub fn gradient(&self, idx: usize) -> f64 {
+        let (z1, c1) = self.points[idx];
+        let (z2, c2) = self.points[idx + 1];
+        (c2 - c1) / (z2 - z1)
+    }
+
+    // Index of the layer containing the given depth,
+    // at layer boundaries - the layer below if down, the layer above otherwise
+    fn layer_index(&self, z: f64, down: bool) -> usize {
+
+        let n = self.points.len() - 1;
+        let idx = if down {
+            self.points.iter().take(n).rposition(|p| p.0 <= z).unwrap_or(0)
+        } else {
+            self.points.iter().take(n).rposition(|p| p.0 < z).unwrap_or(0)
+        };
+
+        idx.min(n - 1)
+    }
+}
+
+/// Kind of a ray point
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RayEvent {
+    /// the starting point
+    Launch,
+    /// crossing of a layer boundary
+    Layer,
+    /// turning point (the ray is horizontal)
+    Turning,
+    /// reflection from the water surface
+    Surface,
+    /// reflection from the bottom
+    Bottom,
+    /// the last point of the ray
+    End,
+}
+
+/// A point of a ray
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayPoint {
+    /// horizontal range, m
+    pub r: f64,
+    /// depth, m
+    pub z: f64,
+    /// travel time, s
+    pub t: f64,
+    /// path length, m
+    pub s: f64,
+    /// grazing angle, rad (positive - downwards, after reflection for reflection points)
+    pub theta: f64,
+    pub event: RayEvent,
+}
+
+/// A ray traced through a sound speed profile
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ray {
+    /// launch grazing angle, rad
+    pub theta0: f64,
+    /// points of the ray: launch, layer boundaries, turning points, reflections and end
+    pub points: Vec<RayPoint>,
+    pub surface_bounces: usize,
+    pub bottom_bounces: usize,
+    pub turning_points: usize,
+    /// the ray was stopped at PHX_RAY_MAX_EVENTS points before reaching the required range
+    pub truncated: bool,
+}
+
+impl Ray {
+
+    /// The last point of the ray
+    pub fn end(&self) -> &RayPoint {
+        &self.points[self.points.len() - 1]
+    }
+}
+
+// A piece of a ray arc between two points with grazing angles of the same sign
+// xi - ray parameter cos(theta) / c, s/m
+// g - sound speed gradient, 1/s
+// (z1, c1, theta1), (z2, c2, theta2) - depth, sound speed and grazing angle at the ends
+// Returns (dr, dt, ds)
+fn phx_ray_segment(xi: f64, g: f64, (z1, c1, theta1): (f64, f64, f64), (z2, c2, theta2): (f64, f64, f64)) -> (f64, f64, f64) {
+
+    let (s1, s2) = (theta1.sin().abs(), theta2.sin().abs());
+    let dz = (z2 - z1).abs();
+
+    // dr = (sin(theta1) - sin(theta2)) / (xi * g), rearranged to hold for vertical and straight rays
+    let dr = if s1 + s2 > 0.0 { xi * (c1 + c2) * dz / (s1 + s2) } else { 0.0 };
+
+    let dth = (theta1 - theta2).abs();
+    let ds = if dth > 1E-9 { dth / (xi * g).abs() } else { dr.hypot(dz) };
+
+    // dt = (atanh(sin(theta1)) - atanh(sin(theta2))) / g for downgoing rays
+    let dt = if (c2 - c1).abs() > 1E-12 * c1 {
+        let sign = if z2 > z1 { 1.0 } else { -1.0 };
+        (((1.0 + s1) / (1.0 + s2)).ln() + (c2 / c1).ln()) / (sign * g)
+    } else {
+        ds / c1
+    };
+
+    (dr, dt, ds)
+}
+
+// Traces a ray from the given point up to the given horizontal range
+// ssp - sound speed profile
+// z0 - source depth, m
+// theta0 - launch grazing angle, rad (positive - downwards), -pi/2..pi/2 not including the ends
+// r_max - horizontal range to trace the ray to, m
+// The ray ends at r_max, or earlier if it has too many points (e.g. steep rays), then it is marked as truncated
+pub fn phx_trace_ray(ssp: &SoundSpeedProfile, z0: f64, theta0: f64, r_max: f64) -> Result<Ray, PhxError> {
+    phx_trace_ray_bounded(ssp, z0, theta0, r_max, usize::MAX)
+}
+
+// Traces a ray, see phx_trace_ray, which is stopped after max_bounces reflections
+fn phx_trace_ray_bounded(ssp: &SoundSpeedProfile, z0: f64, theta0: f64, r_max: f64, max_bounces: usize) -> Result<Ray, PhxError> {
+
+    let z_max = ssp.max_depth();
+    if !(z0 >= 0.0 && z0 <= z_max) {
+        return Err(PhxError::DepthOutOfRange { value: z0, min: 0.0, max: z_max });
+    }
+
+    if !(theta0 > -FRAC_PI_2 && theta0 < FRAC_PI_2) {
+        return Err(PhxError::AngleOutOfRange { value: theta0, min: -FRAC_PI_2, max: FRAC_PI_2 });
+    }
+
+    let xi = theta0.cos() / ssp.speed_at(z0);
+    let mut ray = Ray { theta0, points: Vec::new(), surface_bounces: 0, bottom_bounces: 0, turning_points: 0, truncated: false };
+    let mut pt = RayPoint { r: 0.0, z: z0, t: 0.0, s: 0.0, theta: theta0, event: RayEvent::Launch };
+    ray.points.push(pt);
+
+    while pt.r < r_max && ray.points.len() < PHX_RAY_MAX_EVENTS && ray.surface_bounces + ray.bottom_bounces <= max_bounces {
+
+        // reflections
+        if pt.z <= 0.0 && pt.theta < 0.0 {
+            pt = RayPoint { theta: -pt.theta, event: RayEvent::Surface, ..pt };
+            ray.surface_bounces += 1;
+            ray.points.push(pt);
+        } else if pt.z >= z_max && pt.theta > 0.0 {
+            pt = RayPoint { theta: -pt.theta, event: RayEvent::Bottom, ..pt };
+            ray.bottom_bounces += 1;
+            ray.points.push(pt);
+        }
+
+        // direction of propagation, a horizontal ray bends towards lower sound speed
+        let down = if pt.theta != 0.0 {
+            Some(pt.theta > 0.0)
+        } else {
+            let g_above = if pt.z > 0.0 { ssp.gradient(ssp.layer_index(pt.z, false)) } else { 0.0 };
+            let g_below = if pt.z < z_max { ssp.gradient(ssp.layer_index(pt.z, true)) } else { 0.0 };
+            if g_above > 0.0 {
+                Some(false)
+            } else if g_below < 0.0 {
+                Some(true)
+            } else {
+                None
+            }
+        };
+
+        let down = match down {
+            Some(down) => down,
+            None => {
+                // horizontal ray in a sound speed minimum or an isovelocity layer
+                let c = ssp.speed_at(pt.z);
+                let dr = r_max - pt.r;
+                pt = RayPoint { r: r_max, t: pt.t + dr / c, s: pt.s + dr, event: RayEvent::End, ..pt };
+                break;
+            },
+        };
+
+        let idx = ssp.layer_index(pt.z, down);
+        let g = ssp.gradient(idx);
+        let (z_a, c_a) = ssp.points[idx];
+        let (z_b, c_b) = ssp.points[idx + 1];
+        let c1 = c_a + g * (pt.z - z_a);
+        let (z_t, c_t) = if down { (z_b, c_b) } else { (z_a, c_a) };
+
+        // the next point: layer boundary or turning point
+        let (z2, c2, theta2, event) = if xi * c_t < 1.0 {
+            let theta2 = (xi * c_t).acos();
+            let event = if z_t <= 0.0 || z_t >= z_max { RayEvent::End } else { RayEvent::Layer };
+            (z_t, c_t, if down { theta2 } else { -theta2 }, event)
+        } else {
+            let z_turn = (z_a + (1.0 / xi - c_a) / g).clamp(z_a, z_b);
+            (z_turn, 1.0 / xi, 0.0, RayEvent::Turning)
+        };
+
+        let (dr, dt, ds) = phx_ray_segment(xi, g, (pt.z, c1, pt.theta), (z2, c2, theta2));
+
+        if pt.r + dr >= r_max {
+
+            // the end of the ray is within the segment
+            let dr = r_max - pt.r;
+            let (sin1, cos1) = pt.theta.sin_cos();
+            let sin_e = (sin1 - xi * g * dr).clamp(-1.0, 1.0);
+            let theta_e = sin_e.asin();
+            let z_e = (pt.z + dr * (sin1 + sin_e) / (cos1 + theta_e.cos())).clamp(z_a, z_b);
+            let c_e = c_a + g * (z_e - z_a);
+            let (_, dt, ds) = phx_ray_segment(xi, g, (pt.z, c1, pt.theta), (z_e, c_e, theta_e));
+
+            pt = RayPoint { r: r_max, z: z_e, t: pt.t + dt, s: pt.s + ds, theta: theta_e, event: RayEvent::End };
+            break;
+        }
+
+        if event == RayEvent::Turning {
+            ray.turning_points += 1;
+        }
+
+        pt = RayPoint { r: pt.r + dr, z: z2, t: pt.t + dt, s: pt.s + ds, theta: theta2, event };
+
+        // reflection points are added at the beginning of the next step
+        if event != RayEvent::End {
+            ray.points.push(pt);
+        }
+    }
+
+    ray.truncated = pt.r < r_max && ray.points.len() >= PHX_RAY_MAX_EVENTS;
+    ray.points.push(RayPoint { event: RayEvent::End, ..pt });
+    Ok(ray)
+}
+
+const PHX_EIGENRAY_FAN_SIZE: usize = 1001; // number of launch angles to bracket eigenrays
+
+/// A ray connecting a source and a receiver
+#[derive(Debug, Clone, PartialEq)]
+pub struct Eigenray {
+    /// launch grazing angle, rad (positive - downwards)
+    pub theta0: f64,
+    /// grazing angle at the receiver, rad (positive - downwards)
+    pub theta: f64,
+    /// travel time, s
+    pub t: f64,
+    /// path length, m
+    pub s: f64,
+    pub surface_bounces: usize,
+    pub bottom_bounces: usize,
+    pub turning_points: usize,
+}
+
+impl Eigenray {
+
+    /// The ray is not reflected from the surface or the bottom
+    pub fn is_direct(&self) -> bool {
+        self.surface_bounces == 0 && self.bottom_bounces == 0
+    }
+
+    /// Slant range which corresponds to the travel time at the given sound speed, m
+    /// c - sound speed, m/s
+    pub fn slant_range(&self, c: f64) -> f64 {
+        self.t * c
+    }
+}
+
+// Depth error of a ray at the receiver range, None if the ray has not reached it
+// (it is reflected too many times or truncated)
+fn phx_eigenray_miss(ssp: &SoundSpeedProfile, z_src: f64, z_rx: f64, r: f64, theta0: f64, max_bounces: usize) -> Option<(f64, Ray)> {
+
+    match phx_trace_ray_bounded(ssp, z_src, theta0, r, max_bounces) {
+        Ok(ray) => {
+            let end = *ray.end();
+            if !ray.truncated && end.r >= r && ray.surface_bounces + ray.bottom_bounces <= max_bounces {
+                Some((end.z - z_rx, ray))
+            } else {
+                None
+            }
+        },
+        Err(_) => None,
+    }
+}
+
+// Finds rays connecting a source and a receiver
+// ssp - sound speed profile
+// z_src - source depth, m
+// z_rx - receiver depth, m
+// r - horizontal range between the source and the receiver, m
+// max_bounces - maximal number of surface and bottom reflections
+// Returns eigenrays ordered by the travel time, the first one is usually the direct ray
+pub fn phx_find_eigenrays(ssp: &SoundSpeedProfile, z_src: f64, z_rx: f64, r: f64, max_bounces: usize) -> Result<Vec<Eigenray>, PhxError> {
+
+    let z_max = ssp.max_depth();
+    for &z in [ z_src, z_rx ].iter() {
+        if !(z >= 0.0 && z <= z_max) {
+            return Err(PhxError::DepthOutOfRange { value: z, min: 0.0, max: z_max });
+        }
+    }
+
+    // a fan of launch angles, which does not include the vertical ones
+    let n = PHX_EIGENRAY_FAN_SIZE;
+    let d_theta = PI / ((n + 1) as f64);
+    let fan: Vec<(f64, Option<(f64, Ray)>)> = (1..=n).map(|i| {
+        let theta0 = -FRAC_PI_2 + (i as f64) * d_theta;
+        (theta0, phx_eigenray_miss(ssp, z_src, z_rx, r, theta0, max_bounces))
+    }).collect();
+
+    let mut eigenrays: Vec<Eigenray> = Vec::new();
+
+    for pair in fan.windows(2) {
+
+        let (mut theta_a, mut theta_b) = (pair[0].0, pair[1].0);
+        let (mut miss_a, ray_a, miss_b) = match (&pair[0].1, &pair[1].1) {
+            (Some((miss_a, ray_a)), Some((miss_b, _))) => (*miss_a, ray_a.clone(), *miss_b),
+            _ => continue,
+        };
+
+        // a root exactly at the next fan angle is found with the next pair
+        if miss_a != 0.0 && (miss_a.signum() == miss_b.signum() || miss_b == 0.0) {
+            continue;
+        }
+
+        // bisection
+        let mut best = (miss_a.abs(), ray_a);
+        for _ in 0..100 {
+
+            let theta_m = 0.5 * (theta_a + theta_b);
+            let (miss_m, ray_m) = match phx_eigenray_miss(ssp, z_src, z_rx, r, theta_m, max_bounces) {
+                Some(m) => m,
+                None => break,
+            };
+
+            if miss_m.abs() <= best.0 {
+                best = (miss_m.abs(), ray_m);
+            }
+
+            if miss_m == 0.0 || theta_b - theta_a < 1E-14 {
+                break;
+            }
+
+            if miss_m.signum() == miss_a.signum() {
+                theta_a = theta_m;
+                miss_a = miss_m;
+            } else {
+                theta_b = theta_m;
+            }
+        }
+
+        // discarding false roots at discontinuities, e.g. between reflected families
+        let (miss, ray) = best;
+        if miss < 1E-3 {
+            let end = ray.end();
+            eigenrays.push(Eigenray {
+                theta0: ray.theta0,
+                theta: end.theta,
+                t: end.t,
+                s: end.s,
+                surface_bounces: ray.surface_bounces,
+                bottom_bounces: ray.bottom_bounces,
+                turning_points: ray.turning_points,
+            });
+        }
+    }
+
+    eigenrays.sort_by(|a, b| a.t.partial_cmp(&b.t).unwrap());
+    Ok(eigenrays)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{assert_approx_eq, phx_speed_of_sound_unesco_calc, phx_vertical_sound_path_exact_ts_profile};
+    use crate::{PHX_ATM_PRESSURE_MBAR, PHX_GRAVITY_ACC_MPS2};
+
+    #[test]
+    fn sound_speed_profile_test() {
+
+        let ssp = SoundSpeedProfile::new(vec![ (0.0, 1500.0), (100.0, 1490.0), (1000.0, 1505.0) ]).unwrap();
+        assert_approx_eq!(ssp.speed_at(50.0), 1495.0, 1E-9);
+        assert_approx_eq!(ssp.speed_at(550.0), 1497.5, 1E-9);
+        assert_approx_eq!(ssp.speed_at(2000.0), 1505.0, 1E-9);
+        assert_approx_eq!(ssp.gradient(0), -0.1, 1E-12);
+
+        assert_eq!(SoundSpeedProfile::new(vec![ (0.0, 1500.0) ]), Err(PhxError::TooFewPoints { count: 1 }));
+        assert_eq!(SoundSpeedProfile::new(vec![ (0.0, 1500.0), (0.0, 1490.0) ]), Err(PhxError::NonMonotonicDepth { index: 1 }));
+        assert_eq!(SoundSpeedProfile::new(vec![ (0.0, 1500.0), (10.0, -1.0) ]), Err(PhxError::InvalidSoundSpeed { index: 1 }));
+        assert_eq!(SoundSpeedProfile::new(vec![ (1.0, 1500.0), (10.0, 1500.0) ]), Err(PhxError::NotAtSurface { z: 1.0 }));
+
+        // the same sound speed as in the vertical sound path integrators: absolute pressure
+        let tuples = [ (0.0, 20.0, 35.0), (1000.0, 4.0, 35.0) ];
+        let ts = TsProfile::from_tuples(&tuples).unwrap();
+        let ssp = SoundSpeedProfile::from_ts_profile(&ts, PHX_GRAVITY_ACC_MPS2, EquationOfState::Eos80, SoundSpeedModel::ChenMillero);
+        assert_approx_eq!(ssp.points()[0].1, phx_speed_of_sound_unesco_calc(20.0, PHX_ATM_PRESSURE_MBAR, 35.0), 1E-9);
+        assert!(ssp.points()[1].1 < ssp.points()[0].1);
+
+        // a layer of linear sound speed: z = c1 * (exp(g * t) - 1) / g
+        let (c1, g) = (ssp.points()[0].1, ssp.gradient(0));
+        assert_approx_eq!(phx_vertical_sound_path_exact_ts_profile(0.5, PHX_GRAVITY_ACC_MPS2, &tuples), c1 * ((g * 0.5).exp() - 1.0) / g, 1E-6);
+    }
+
+    #[test]
+    fn phx_trace_ray_isovelocity_test() {
+
+        // straight rays and mirror reflections
+        let ssp = SoundSpeedProfile::new(vec![ (0.0, 1500.0), (100.0, 1500.0) ]).unwrap();
+        let theta0 = 30.0f64.to_radians();
+
+        let ray = phx_trace_ray(&ssp, 50.0, theta0, 50.0).unwrap();
+        let end = ray.end();
+        assert_approx_eq!(end.z, 50.0 + 50.0 * theta0.tan(), 1E-9);
+        assert_approx_eq!(end.s, 50.0 / theta0.cos(), 1E-9);
+        assert_approx_eq!(end.t, end.s / 1500.0, 1E-12);
+        assert_approx_eq!(end.theta, theta0, 1E-12);
+
+        // bottom and surface bounces, the unfolded path is a straight line
+        let ray = phx_trace_ray(&ssp, 50.0, theta0, 1000.0).unwrap();
+        let unfolded = 50.0 + 1000.0 * theta0.tan();
+        assert_eq!(ray.bottom_bounces, ((unfolded + 100.0) / 200.0) as usize);
+        assert_eq!(ray.surface_bounces, (unfolded / 200.0) as usize);
+        assert_approx_eq!(ray.end().t, 1000.0 / theta0.cos() / 1500.0, 1E-9);
+        assert_approx_eq!(ray.end().r, 1000.0, 1E-9);
+        assert!(ray.points.iter().filter(|p| p.event == RayEvent::Surface).all(|p| p.z == 0.0 && p.theta > 0.0));
+
+        assert!(phx_trace_ray(&ssp, 150.0, theta0, 100.0).is_err());
+        assert!(phx_trace_ray(&ssp, 50.0, FRAC_PI_2, 100.0).is_err());
+        assert!(!ray.truncated);
+
+        // a steep ray in a shallow layer has too many reflections to reach the range
+        let shallow = SoundSpeedProfile::new(vec![ (0.0, 1500.0), (1.0, 1500.0) ]).unwrap();
+        let ray = phx_trace_ray(&shallow, 0.5, 80.0f64.to_radians(), 100000.0).unwrap();
+        assert!(ray.truncated);
+        assert!(ray.end().r < 100000.0);
+        assert_eq!(ray.points.len(), PHX_RAY_MAX_EVENTS + 1);
+        // and is not taken as an eigenray candidate
+        assert!(phx_eigenray_miss(&shallow, 0.5, 0.5, 100000.0, 80.0f64.to_radians(), usize::MAX).is_none());
+    }
+
+    #[test]
+    fn phx_trace_ray_gradient_test() {
+
+        // constant gradient: the ray is an arc of a circle with the radius 1 / (xi * g)
+        let g = 0.05;
+        let ssp = SoundSpeedProfile::new(vec![ (0.0, 1500.0), (500.0, 1500.0 + 500.0 * g), (5000.0, 1500.0 + 5000.0 * g) ]).unwrap();
+        let theta0 = 10.0f64.to_radians();
+        let xi = theta0.cos() / 1500.0;
+        let radius = 1.0 / (xi * g);
+
+        // turning point
+        let ray = phx_trace_ray(&ssp, 0.0, theta0, 1.5 * radius * theta0.sin()).unwrap();
+        let turning = ray.points.iter().find(|p| p.event == RayEvent::Turning).unwrap();
+        assert_eq!(ray.turning_points, 1);
+        assert_approx_eq!(turning.r, radius * theta0.sin(), 1E-6);
+        assert_approx_eq!(turning.z, radius * (1.0 - theta0.cos()), 1E-6);
+        assert_approx_eq!(turning.theta, 0.0, 1E-12);
+        assert_approx_eq!(turning.s, radius * theta0, 1E-6);
+
+        // closed form travel time along the arc
+        assert_approx_eq!(turning.t, theta0.sin().atanh() / g, 1E-9);
+
+        // the ray comes back to the surface symmetrically
+        let ray = phx_trace_ray(&ssp, 0.0, theta0, 2.0 * radius * theta0.sin() + 1.0).unwrap();
+        let surface = ray.points.iter().find(|p| p.event == RayEvent::Surface).unwrap();
+        assert_approx_eq!(surface.r, 2.0 * radius * theta0.sin(), 1E-6);
+        assert_approx_eq!(surface.t, 2.0 * turning.t, 1E-9);
+        assert_approx_eq!(surface.theta, theta0, 1E-9);
+        assert_eq!(ray.surface_bounces, 1);
+
+        // travel time and path length grow monotonically
+        assert!(ray.points.windows(2).all(|p| p[1].t >= p[0].t && p[1].s >= p[0].s && p[1].r >= p[0].r));
+
+        // a ray in a sound speed minimum is trapped
+        let channel = SoundSpeedProfile::new(vec![ (0.0, 1520.0), (1000.0, 1490.0), (4000.0, 1540.0) ]).unwrap();
+        let ray = phx_trace_ray(&channel, 1000.0, 5.0f64.to_radians(), 100000.0).unwrap();
+        assert_eq!(ray.surface_bounces + ray.bottom_bounces, 0);
+        assert!(ray.turning_points > 2);
+        assert!(ray.points.iter().all(|p| p.z > 0.0 && p.z < 4000.0));
+        assert_approx_eq!(ray.end().r, 100000.0, 1E-6);
+    }
+
+    #[test]
+    fn phx_find_eigenrays_isovelocity_test() {
+
+        // straight rays and image sources
+        let c = 1500.0;
+        let ssp = SoundSpeedProfile::new(vec![ (0.0, c), (100.0, c) ]).unwrap();
+        let (z_src, z_rx, r) = (20.0, 60.0, 500.0);
+
+        let eigenrays = phx_find_eigenrays(&ssp, z_src, z_rx, r, 1).unwrap();
+        assert_eq!(eigenrays.len(), 3);
+
+        let direct = &eigenrays[0];
+        assert!(direct.is_direct());
+        assert_approx_eq!(direct.theta0, ((z_rx - z_src) / r).atan(), 1E-9);
+        assert_approx_eq!(direct.t, r.hypot(z_rx - z_src) / c, 1E-9);
+        assert_approx_eq!(direct.s, r.hypot(z_rx - z_src), 1E-6);
+        assert_approx_eq!(direct.slant_range(c), r.hypot(z_rx - z_src), 1E-6);
+
+        let surface = eigenrays.iter().find(|e| e.surface_bounces == 1).unwrap();
+        assert_eq!(surface.bottom_bounces, 0);
+        assert_approx_eq!(surface.theta0, -((z_rx + z_src) / r).atan(), 1E-9);
+        assert_approx_eq!(surface.t, r.hypot(z_rx + z_src) / c, 1E-9);
+
+        let bottom = eigenrays.iter().find(|e| e.bottom_bounces == 1).unwrap();
+        assert_approx_eq!(bottom.t, r.hypot(200.0 - z_rx - z_src) / c, 1E-9);
+
+        assert!(phx_find_eigenrays(&ssp, z_src, 120.0, r, 1).is_err());
+    }
+
+    #[test]
+    fn phx_find_eigenrays_gradient_test() {
+
+        let ts = TsProfile::from_tuples(&[ (0.0, 18.0, 35.0), (50.0, 16.0, 35.0), (200.0, 10.0, 35.0), (1000.0, 4.0, 35.0) ]).unwrap();
+        let ssp = SoundSpeedProfile::from_ts_profile(&ts, PHX_GRAVITY_ACC_MPS2, EquationOfState::Eos80, SoundSpeedModel::ChenMillero);
+        let (z_src, z_rx, r) = (10.0, 400.0, 2000.0);
+
+        let eigenrays = phx_find_eigenrays(&ssp, z_src, z_rx, r, 0).unwrap();
+        assert_eq!(eigenrays.len(), 1);
+
+        // the eigenray hits the receiver
+        let direct = &eigenrays[0];
+        let ray = phx_trace_ray(&ssp, z_src, direct.theta0, r).unwrap();
+        assert_approx_eq!(ray.end().z, z_rx, 1E-3);
+        assert_approx_eq!(ray.end().t, direct.t, 1E-9);
+
+        // the travel time lies between the ones at the extreme sound speeds
+        let slant = r.hypot(z_rx - z_src);
+        assert!(direct.t > slant / ssp.speed_at(z_src) && direct.t < slant / ssp.speed_at(z_rx));
+        assert!(direct.s > slant);
+    }
+}
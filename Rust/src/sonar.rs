@@ -0,0 +1,193 @@
+// Transmission loss and the sonar equations
+// Urick, R. J. Principles of Underwater Sound, 3rd ed., 1983
+//
+// All levels are in dB:
+// sl - source level, dB re 1 µPa at 1 m
+// nl - noise level in the receiver band, dB re 1 µPa
+// di - directivity index of the receiver, dB
+// dt - detection threshold, dB
+// ts - target strength, dB
+// Ranges are in m, absorption - in dB/km (see AbsorptionModel), spreading loss is referenced to 1 m
+
+/// Geometrical spreading law
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SpreadingLaw {
+    /// 20 log10(r), deep water
+    #[default]
+    Spherical,
+    /// 10 log10(r), waveguide
+    Cylindrical,
+    /// 15 log10(r), an empirical compromise for shallow water
+    Practical,
+    /// spherical up to the transition range (m), cylindrical beyond it
+    Mixed { transition_range: f64 },
+}
+
+impl SpreadingLaw {
+
+    /// Spreading loss at the given range, dB, ranges less than 1 m are treated as 1 m
+    /// r - range, m
+    pub fn loss(&self, r: f64) -> f64 {
+
+        let r = r.max(1.0);
+
+        match *self {
+            SpreadingLaw::Spherical => 20.0 * r.log10(),
+            SpreadingLaw::Cylindrical => 10.0 * r.log10(),
+            SpreadingLaw::Practical => 15.0 * r.log10(),
+            SpreadingLaw::Mixed { transition_range } => {
+                let r0 = transition_range.max(1.0);
+                if r <= r0 {
+                    20.0 * r.log10()
+                } else {
+                    20.0 * r0.log10() + 10.0 * (r / r0).log10()
+                }
+            },
+        }
+    }
+}
+
+// Calculates one-way transmission loss, dB
+// r - range, m
+// alpha - absorption, dB/km
+// spreading - geometrical spreading law
+pub fn phx_transmission_loss_calc(r: f64, alpha: f64, spreading: SpreadingLaw) -> f64 {
+    spreading.loss(r) + alpha * r / 1000.0
+}
+
+// Calculates the range (m) at which one-way transmission loss reaches the given value,
+// inverse of phx_transmission_loss_calc
+// tl - transmission loss, dB
+// alpha - absorption, dB/km
+// spreading - geometrical spreading law
+// Returns None if the loss is less than the loss at 1 m
+pub fn phx_range_by_transmission_loss_calc(tl: f64, alpha: f64, spreading: SpreadingLaw) -> Option<f64> {
+
+    if tl.is_nan() || tl < phx_transmission_loss_calc(1.0, alpha, spreading) {
+        return None;
+    }
+
+    // bracketing the root, transmission loss grows monotonically with range
+    let mut r_min = 1.0;
+    let mut r_max = 2.0;
+    while phx_transmission_loss_calc(r_max, alpha, spreading) < tl {
+        r_min = r_max;
+        r_max *= 2.0;
+    }
+
+    for _ in 0..200 {
+        let r = 0.5 * (r_min + r_max);
+        if phx_transmission_loss_calc(r, alpha, spreading) < tl {
+            r_min = r;
+        } else {
+            r_max = r;
+        }
+
+        if r_max - r_min < 1E-9 * r_max {
+            break;
+        }
+    }
+
+    Some(0.5 * (r_min + r_max))
+}
+
+// Calculates signal excess of the passive sonar equation, dB
+// SE = SL - TL - (NL - DI) - DT
+pub fn phx_passive_signal_excess_calc(sl: f64, tl: f64, nl: f64, di: f64, dt: f64) -> f64 {
+    sl - tl - (nl - di) - dt
+}
+
+// Calculates signal excess of the noise-limited monostatic active sonar equation, dB
+// SE = SL - 2TL + TS - (NL - DI) - DT
+// tl - one-way transmission loss, dB
+pub fn phx_active_signal_excess_calc(sl: f64, tl: f64, ts: f64, nl: f64, di: f64, dt: f64) -> f64 {
+    sl - 2.0 * tl + ts - (nl - di) - dt
+}
+
+// Calculates the maximal detection range of a passive sonar (or an acoustic link), m
+// alpha - absorption, dB/km
+// spreading - geometrical spreading law
+// Returns None if the signal cannot be detected even at 1 m
+pub fn phx_passive_max_range_calc(sl: f64, nl: f64, di: f64, dt: f64, alpha: f64, spreading: SpreadingLaw) -> Option<f64> {
+    phx_range_by_transmission_loss_calc(phx_passive_signal_excess_calc(sl, 0.0, nl, di, dt), alpha, spreading)
+}
+
+// Calculates the maximal detection range of a monostatic active sonar, m
+// alpha - absorption, dB/km
+// spreading - geometrical spreading law
+// Returns None if the target cannot be detected even at 1 m
+pub fn phx_active_max_range_calc(sl: f64, ts: f64, nl: f64, di: f64, dt: f64, alpha: f64, spreading: SpreadingLaw) -> Option<f64> {
+    phx_range_by_transmission_loss_calc(phx_active_signal_excess_calc(sl, 0.0, ts, nl, di, dt) / 2.0, alpha, spreading)
+}
+
+// Calculates the source level required for a passive sonar (or an acoustic link)
+// to have zero signal excess, dB re 1 µPa at 1 m
+// tl - one-way transmission loss, dB
+pub fn phx_passive_required_source_level_calc(tl: f64, nl: f64, di: f64, dt: f64) -> f64 {
+    tl + nl - di + dt
+}
+
+// Calculates the source level required for a monostatic active sonar
+// to have zero signal excess, dB re 1 µPa at 1 m
+// tl - one-way transmission loss, dB
+pub fn phx_active_required_source_level_calc(tl: f64, ts: f64, nl: f64, di: f64, dt: f64) -> f64 {
+    2.0 * tl - ts + nl - di + dt
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::assert_approx_eq;
+    use crate::alpha_e_francois_garrison_calc;
+
+    #[test]
+    fn phx_transmission_loss_calc_test() {
+
+        assert_approx_eq!(phx_transmission_loss_calc(1000.0, 0.0, SpreadingLaw::Spherical), 60.0, 1E-9);
+        assert_approx_eq!(phx_transmission_loss_calc(1000.0, 0.0, SpreadingLaw::Cylindrical), 30.0, 1E-9);
+        assert_approx_eq!(phx_transmission_loss_calc(1000.0, 0.0, SpreadingLaw::Practical), 45.0, 1E-9);
+        assert_approx_eq!(phx_transmission_loss_calc(1000.0, 2.0, SpreadingLaw::Spherical), 62.0, 1E-9);
+        assert_approx_eq!(phx_transmission_loss_calc(0.5, 2.0, SpreadingLaw::Spherical), 0.001, 1E-9);
+
+        // spherical to 100 m, then cylindrical: 40 + 10 dB at 1 km
+        let mixed = SpreadingLaw::Mixed { transition_range: 100.0 };
+        assert_approx_eq!(mixed.loss(50.0), SpreadingLaw::Spherical.loss(50.0), 1E-9);
+        assert_approx_eq!(mixed.loss(1000.0), 50.0, 1E-9);
+
+        let alpha = alpha_e_francois_garrison_calc(12.0, 10.0, 35.0, 100.0, 8.0);
+        for &tl in [ 0.5, 20.0, 55.5, 80.0, 120.0 ].iter() {
+            for &law in [ SpreadingLaw::Spherical, SpreadingLaw::Practical, mixed ].iter() {
+                let r = phx_range_by_transmission_loss_calc(tl, alpha, law).unwrap();
+                assert_approx_eq!(phx_transmission_loss_calc(r, alpha, law), tl, 1E-6);
+            }
+        }
+
+        assert_eq!(phx_range_by_transmission_loss_calc(0.0, alpha, SpreadingLaw::Spherical), None);
+    }
+
+    #[test]
+    fn phx_sonar_equation_test() {
+
+        let (sl, nl, di, dt, ts) = (180.0, 70.0, 10.0, 10.0, 15.0);
+        let alpha = 1.0;
+        let law = SpreadingLaw::Spherical;
+
+        assert_approx_eq!(phx_passive_signal_excess_calc(sl, 60.0, nl, di, dt), 50.0, 1E-9);
+        assert_approx_eq!(phx_active_signal_excess_calc(sl, 60.0, ts, nl, di, dt), 5.0, 1E-9);
+
+        // zero signal excess at the maximal range
+        let r = phx_passive_max_range_calc(sl, nl, di, dt, alpha, law).unwrap();
+        assert_approx_eq!(phx_passive_signal_excess_calc(sl, phx_transmission_loss_calc(r, alpha, law), nl, di, dt), 0.0, 1E-6);
+
+        let r_active = phx_active_max_range_calc(sl, ts, nl, di, dt, alpha, law).unwrap();
+        assert!(r_active < r);
+        assert_approx_eq!(phx_active_signal_excess_calc(sl, phx_transmission_loss_calc(r_active, alpha, law), ts, nl, di, dt), 0.0, 1E-6);
+
+        // the required source level at the maximal range is the source level
+        assert_approx_eq!(phx_passive_required_source_level_calc(phx_transmission_loss_calc(r, alpha, law), nl, di, dt), sl, 1E-6);
+        assert_approx_eq!(phx_active_required_source_level_calc(phx_transmission_loss_calc(r_active, alpha, law), ts, nl, di, dt), sl, 1E-6);
+
+        assert_eq!(phx_passive_max_range_calc(60.0, nl, di, dt, alpha, law), None);
+    }
+}
@@ -0,0 +1,266 @@
+// Alternative equations for the speed of sound in seawater
+// Pressure-based equations take pressure in mBar, depth-based ones - depth in m
+
+use crate::phx_speed_of_sound_unesco_calc;
+use crate::teos10;
+
+const PHX_MBAR_PER_KGF_CM2: f64 = 980.665; // 1 kg/cm^2 in mBar
+
+/// Sound speed equation
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SoundSpeedModel {
+    /// Chen and Millero (1977), the UNESCO equation, see phx_speed_of_sound_unesco_calc
+    #[default]
+    ChenMillero,
+    /// Del Grosso (1974)
+    DelGrosso,
+    /// Mackenzie (1981)
+    Mackenzie,
+    /// Coppens (1981)
+    Coppens,
+    /// Medwin (1975)
+    Medwin,
+    /// Wilson (1960)
+    Wilson,
+    /// TEOS-10 75-term polynomial, salinity and temperature are converted
+    /// to Reference Salinity and Conservative Temperature
+    Teos10,
+}
+
+/// Published validity domain of a sound speed equation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SoundSpeedValidity {
+    /// temperature range, °C
+    pub t_min: f64,
+    pub t_max: f64,
+    /// salinity range, PSU
+    pub s_min: f64,
+    pub s_max: f64,
+    /// maximal pressure, mBar (infinite for depth-based equations)
+    pub p_max: f64,
+    /// maximal depth, m (infinite for pressure-based equations)
+    pub z_max: f64,
+}
+
+impl SoundSpeedValidity {
+
+    /// Checks if the given temperature (°C), pressure (mBar), salinity (PSU) and depth (m)
+    /// are within the validity domain
+    pub fn contains(&self, t: f64, p: f64, s: f64, z: f64) -> bool {
+        t >= self.t_min && t <= self.t_max &&
+        s >= self.s_min && s <= self.s_max &&
+        p >= 0.0 && p <= self.p_max &&
+        z >= 0.0 && z <= self.z_max
+    }
+}
+
+impl SoundSpeedModel {
+
+    /// Calculates the speed of sound in water, m/s
+    /// t - temperature, °C
+    /// p - pressure, mBar
+    /// s - salinity, PSU
+    /// z - depth, m
+    /// Pressure-based equations ignore depth and vice versa
+    pub fn speed_calc(&self, t: f64, p: f64, s: f64, z: f64) -> f64 {
+        match *self {
+            SoundSpeedModel::ChenMillero => phx_speed_of_sound_unesco_calc(t, p, s),
+            SoundSpeedModel::DelGrosso => phx_speed_of_sound_del_grosso_calc(t, p, s),
+            SoundSpeedModel::Mackenzie => phx_speed_of_sound_mackenzie_calc(t, z, s),
+            SoundSpeedModel::Coppens => phx_speed_of_sound_coppens_calc(t, z, s),
+            SoundSpeedModel::Medwin => phx_speed_of_sound_medwin_calc(t, z, s),
+            SoundSpeedModel::Wilson => phx_speed_of_sound_wilson_calc(t, p, s),
+            SoundSpeedModel::Teos10 => {
+                let sa = teos10::phx_teos10_sa_from_sp_calc(s, 0.0);
+                teos10::phx_teos10_sound_speed_calc(sa, teos10::phx_teos10_ct_from_t_calc(sa, t, p), p)
+            },
+        }
+    }
+
+    /// Published validity domain of the equation
+    pub fn validity(&self) -> SoundSpeedValidity {
+
+        let (t_min, t_max, s_min, s_max, p_max, z_max) = match *self {
+            SoundSpeedModel::ChenMillero => (0.0, 40.0, 0.0, 40.0, 1.0E6, f64::INFINITY),
+            SoundSpeedModel::DelGrosso => (0.0, 30.0, 30.0, 40.0, 1000.0 * PHX_MBAR_PER_KGF_CM2, f64::INFINITY),
+            SoundSpeedModel::Mackenzie => (-2.0, 30.0, 25.0, 40.0, f64::INFINITY, 8000.0),
+            SoundSpeedModel::Coppens => (0.0, 35.0, 0.0, 45.0, f64::INFINITY, 4000.0),
+            SoundSpeedModel::Medwin => (0.0, 35.0, 0.0, 45.0, f64::INFINITY, 1000.0),
+            SoundSpeedModel::Wilson => (-4.0, 30.0, 33.0, 37.0, 1000.0 * PHX_MBAR_PER_KGF_CM2, f64::INFINITY),
+            SoundSpeedModel::Teos10 => (-2.0, 40.0, 0.0, 42.0, 8.0E5, f64::INFINITY),
+        };
+
+        SoundSpeedValidity { t_min, t_max, s_min, s_max, p_max, z_max }
+    }
+}
+
+// Del Grosso, V. A. (1974). New equation for the speed of sound in natural waters
+// (with comparisons to other equations). J. Acoust. Soc. Am. 56(4), 1084-1091
+// t - temperature, °C
+// p - pressure, mBar
+// s - salinity, PSU
+pub fn phx_speed_of_sound_del_grosso_calc(t: f64, p: f64, s: f64) -> f64 {
+
+    let p = p / PHX_MBAR_PER_KGF_CM2;
+
+    let c_t = ((0.221649E-3 * t - 0.551184E-1) * t + 0.5012285E1) * t;
+    let c_s = (0.1288598E-3 * s + 0.1329530E1) * s;
+    let c_p = ((-0.8833959E-8 * p + 0.2449993E-4) * p + 0.1560592) * p;
+    let c_stp = -0.1275936E-1 * t * s + 0.6353509E-2 * t * p + 0.2656174E-7 * t * t * p * p -
+                0.1593895E-5 * t * p * p + 0.5222483E-9 * t * p * p * p - 0.4383615E-6 * t * t * t * p -
+                0.1616745E-8 * s * s * p * p + 0.9688441E-4 * t * t * s + 0.4857614E-5 * s * s * t * p -
+                0.3406824E-3 * t * s * p;
+
+    1402.392 + c_t + c_s + c_p + c_stp
+}
+
+// Mackenzie, K. V. (1981). Nine-term equation for sound speed in the oceans.
+// J. Acoust. Soc. Am. 70(3), 807-812
+// t - temperature, °C
+// z - depth, m
+// s - salinity, PSU
+pub fn phx_speed_of_sound_mackenzie_calc(t: f64, z: f64, s: f64) -> f64 {
+
+    let ds = s - 35.0;
+
+    1448.96 + ((2.374E-4 * t - 5.304E-2) * t + 4.591) * t + 1.340 * ds + (1.675E-7 * z + 1.630E-2) * z -
+    1.025E-2 * t * ds - 7.139E-13 * t * z * z * z
+}
+
+// Coppens, A. B. (1981). Simple equations for the speed of sound in Neptunian waters.
+// J. Acoust. Soc. Am. 69(3), 862-863
+// t - temperature, °C
+// z - depth, m
+// s - salinity, PSU
+pub fn phx_speed_of_sound_coppens_calc(t: f64, z: f64, s: f64) -> f64 {
+
+    let t = t / 10.0;
+    let z = z / 1000.0;
+    let ds = s - 35.0;
+
+    let c0 = 1449.05 + ((0.23 * t - 5.21) * t + 45.7) * t + ((0.009 * t - 0.126) * t + 1.333) * ds;
+
+    c0 + (16.23 + 0.253 * t) * z + (0.213 - 0.1 * t) * z * z + (0.016 + 0.0002 * ds) * ds * t * z
+}
+
+// Medwin, H. (1975). Speed of sound in water: A simple equation for realistic parameters.
+// J. Acoust. Soc. Am. 58(6), 1318-1319
+// t - temperature, °C
+// z - depth, m
+// s - salinity, PSU
+pub fn phx_speed_of_sound_medwin_calc(t: f64, z: f64, s: f64) -> f64 {
+    1449.2 + ((0.00029 * t - 0.055) * t + 4.6) * t + (1.34 - 0.01 * t) * (s - 35.0) + 0.016 * z
+}
+
+// Wilson, W. D. (1960). Equation for the speed of sound in sea water.
+// J. Acoust. Soc. Am. 32(10), 1357
+// t - temperature, °C
+// p - pressure, mBar
+// s - salinity, PSU
+pub fn phx_speed_of_sound_wilson_calc(t: f64, p: f64, s: f64) -> f64 {
+
+    let p = p / PHX_MBAR_PER_KGF_CM2;
+    let ds = s - 35.0;
+
+    let v_t = (((7.9851E-6 * t - 2.6045E-4) * t - 4.4532E-2) * t + 4.5721) * t;
+    let v_p = (((-3.3603E-12 * p + 3.5216E-9) * p + 1.0268E-5) * p + 1.60272E-1) * p;
+    let v_s = (1.69202E-3 * ds + 1.39799) * ds;
+    let v_stp = ds * (-1.1244E-2 * t + 7.7711E-7 * t * t + 7.7016E-5 * p - 1.2943E-7 * p * p + 3.1580E-8 * p * t + 1.5790E-9 * p * t * t) +
+                p * (-1.8607E-4 * t + 7.4812E-6 * t * t + 4.5283E-8 * t * t * t) +
+                p * p * (-2.5294E-7 * t + 1.8563E-9 * t * t) +
+                p * p * p * (-1.9646E-10 * t);
+
+    1449.14 + v_t + v_p + v_s + v_stp
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::*;
+
+    const MODELS: [SoundSpeedModel; 7] = [ SoundSpeedModel::ChenMillero, SoundSpeedModel::DelGrosso, SoundSpeedModel::Mackenzie,
+                                           SoundSpeedModel::Coppens, SoundSpeedModel::Medwin, SoundSpeedModel::Wilson,
+                                           SoundSpeedModel::Teos10 ];
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn phx_speed_of_sound_check_values_test() {
+
+        // UNESCO 1983 check value of the Chen and Millero equation: T = 40 °C, P = 1000 bar, S = 40 PSU
+        assert_approx_eq!(SoundSpeedModel::ChenMillero.speed_calc(40.0, 1.0E6, 40.0, 0.0), 1731.995, 1E-3);
+
+        // Mackenzie (1981) check value
+        assert_approx_eq!(phx_speed_of_sound_mackenzie_calc(25.0, 1000.0, 35.0), 1550.744, 1E-3);
+        assert_approx_eq!(SoundSpeedModel::Mackenzie.speed_calc(25.0, 0.0, 35.0, 1000.0), 1550.744, 1E-3);
+
+        // TEOS-10: sound speed of the Standard Ocean, IAPWS 2008 Table 8a,
+        // reproduced by the 75-term polynomial within 1E-2 m/s
+        assert_approx_eq!(SoundSpeedModel::Teos10.speed_calc(0.0, 0.0, 35.0, 0.0), 1449.00246, 1E-2);
+        // the fourth point of the GSW example cast: SA = 34.8472 g/kg, t = 10.26 °C, p = 250 dbar,
+        // where gsw_CT_from_t = 10.226189266620782 °C
+        let sa = 34.8472;
+        assert_approx_eq!(SoundSpeedModel::Teos10.speed_calc(10.26, 2.5E4, sa / teos10::PHX_TEOS10_UPS, 0.0),
+                          teos10::phx_teos10_sound_speed_calc(sa, 10.226189266620782, 2.5E4), 1E-3);
+
+        // constant terms of the equations: T = 0 °C, S = 35 PSU, at the surface
+        assert_approx_eq!(phx_speed_of_sound_wilson_calc(0.0, 0.0, 35.0), 1449.14, 1E-9);
+        assert_approx_eq!(phx_speed_of_sound_medwin_calc(0.0, 0.0, 35.0), 1449.2, 1E-9);
+        assert_approx_eq!(phx_speed_of_sound_coppens_calc(0.0, 0.0, 35.0), 1449.05, 1E-9);
+        assert_approx_eq!(phx_speed_of_sound_del_grosso_calc(0.0, 0.0, 35.0), 1449.0839, 1E-3);
+
+        // values of the equations at T = 15 °C, S = 38 PSU, where every term contributes:
+        // 1000 dbar for the pressure-based ones, 500 m (Medwin) and 1000 m (Coppens) for the depth-based ones
+        assert_approx_eq!(phx_speed_of_sound_del_grosso_calc(15.0, 1.0E5, 38.0), 1526.6376, 1E-3);
+        assert_approx_eq!(phx_speed_of_sound_wilson_calc(15.0, 1.0E5, 38.0), 1527.2683, 1E-3);
+        assert_approx_eq!(phx_speed_of_sound_medwin_calc(15.0, 500.0, 38.0), 1518.3737, 1E-3);
+        assert_approx_eq!(phx_speed_of_sound_coppens_calc(15.0, 1000.0, 38.0), 1526.8937, 1E-3);
+
+        // Coppens (1981) and Mackenzie (1981) reproduce Del Grosso within 0.1 m/s
+        // in the open ocean, Wilson (1960) is known to exceed it by about 0.4 m/s
+        let lat = 45.0f64.to_radians();
+        for &z in [ 0.0, 500.0, 1000.0, 2000.0 ].iter() {
+            let c_dg = phx_speed_of_sound_del_grosso_calc(10.0, unesco::phx_pressure_by_depth_unesco_calc(z, 0.0, lat, 0.0), 35.0);
+            assert_approx_eq!(phx_speed_of_sound_coppens_calc(10.0, z, 35.0), c_dg, 0.1);
+            assert_approx_eq!(phx_speed_of_sound_mackenzie_calc(10.0, z, 35.0), c_dg, 0.1);
+            assert_approx_eq!(phx_speed_of_sound_wilson_calc(10.0, unesco::phx_pressure_by_depth_unesco_calc(z, 0.0, lat, 0.0), 35.0) - c_dg, 0.4, 0.05);
+        }
+    }
+
+    #[test]
+    fn phx_speed_of_sound_models_test() {
+
+        // All the equations should agree with the UNESCO table (see phx_speed_of_sound_calc_test)
+        // within their validity domains for realistic oceanic conditions (warm water only near the surface).
+        // Depth is estimated for a standard ocean at 45° latitude
+        let ref_tpz: [(f64, f64, f64); 11] = [ ( 0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (20.0, 0.0, 0.0), (30.0, 0.0, 0.0),
+                                                ( 0.0, 1E5, 989.50), (10.0, 1E5, 989.50), (20.0, 1E5, 989.50),
+                                                ( 0.0, 2E5, 1974.33), (10.0, 2E5, 1974.33),
+                                                ( 0.0, 4E5, 3930.49), ( 0.0, 8E5, 7792.33) ];
+
+        for model in MODELS.iter() {
+            let validity = model.validity();
+            for &(t, p, z) in ref_tpz.iter() {
+                if validity.contains(t, p, 35.0, z) {
+                    assert_approx_eq!(model.speed_calc(t, p, 35.0, z), phx_speed_of_sound_unesco_calc(t, p, 35.0), 1.2);
+                }
+            }
+        }
+
+        assert!(!SoundSpeedModel::Medwin.validity().contains(10.0, 2E5, 35.0, 2000.0));
+        assert!(SoundSpeedModel::Mackenzie.validity().contains(10.0, 2E5, 35.0, 2000.0));
+        assert!(!SoundSpeedModel::DelGrosso.validity().contains(10.0, 0.0, 20.0, 0.0));
+    }
+
+    #[test]
+    fn phx_vertical_sound_path_models_test() {
+
+        let ts_profile = TsProfile::from_tuples(&[ (0.0, 20.0, 35.0), (100.0, 15.0, 35.0), (1000.0, 5.0, 35.0) ]).unwrap();
+
+        for model in MODELS.iter() {
+            let h = phx_try_vertical_sound_path_ts_profile(0.5, 10000, PHX_GRAVITY_ACC_MPS2, &ts_profile, EquationOfState::Eos80, *model).unwrap();
+            let h_ref = phx_vertical_sound_path_ts_profile(0.5, 10000, PHX_GRAVITY_ACC_MPS2, &[ (0.0, 20.0, 35.0), (100.0, 15.0, 35.0), (1000.0, 5.0, 35.0) ]);
+            assert_approx_eq!(h, h_ref, 1.0);
+        }
+    }
+}
@@ -0,0 +1,188 @@
+// Static stability of the water column: the squared buoyancy (Brunt–Väisälä) frequency
+// by the adiabatic leveling method:
+// Bray, N. A., Fofonoff, N. P. (1981). Available potential energy for MODE eddies.
+// J. Phys. Oceanogr., 11, 30-47
+
+use crate::unesco::{phx_potential_density_calc, phx_pressure_by_depth_unesco_calc};
+use crate::{phx_gravity_constant_wgs84_calc, TsProfile};
+
+/// A layer between two adjacent points of a TS profile
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BuoyancyLayer {
+    /// depth of the upper boundary, m
+    pub z_top: f64,
+    /// depth of the lower boundary, m
+    pub z_bottom: f64,
+    /// squared buoyancy frequency, 1/s^2
+    pub n2: f64,
+}
+
+impl BuoyancyLayer {
+
+    /// Depth of the middle of the layer, m
+    pub fn z_mid(&self) -> f64 {
+        0.5 * (self.z_top + self.z_bottom)
+    }
+
+    pub fn thickness(&self) -> f64 {
+        self.z_bottom - self.z_top
+    }
+
+    /// The layer is statically unstable if N² < 0
+    pub fn is_unstable(&self) -> bool {
+        self.n2 < 0.0
+    }
+
+    /// Buoyancy frequency, rad/s, zero for unstable layers
+    pub fn n(&self) -> f64 {
+        self.n2.max(0.0).sqrt()
+    }
+}
+
+/// Stability summary of a water column
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StabilitySummary {
+    /// maximal squared buoyancy frequency, 1/s^2
+    pub n2_max: f64,
+    /// depth of the middle of the layer with the maximal N², m (the pycnocline)
+    pub z_n2_max: f64,
+    /// thickness-weighted mean of N² over the column, 1/s^2
+    pub n2_mean: f64,
+    /// number of statically unstable layers
+    pub unstable_count: usize,
+    /// total thickness of statically unstable layers, m
+    pub unstable_thickness: f64,
+}
+
+impl StabilitySummary {
+    pub fn is_stable(&self) -> bool {
+        self.unstable_count == 0
+    }
+}
+
+// Calculates the squared buoyancy frequency for every layer of a TS profile, 1/s^2
+// Water parcels from the boundaries of a layer are moved adiabatically to the middle pressure
+// of the layer, and N² = g * (rho_bottom - rho_top) / (rho_mid * dz)
+// ts_profile - vertical Temperature-Salinity profile
+// p0 - atmospheric pressure, mBar
+// lat_rad - latitude, rad
+pub fn phx_buoyancy_frequency_ts_profile(ts_profile: &TsProfile, p0: f64, lat_rad: f64) -> Vec<BuoyancyLayer> {
+
+    let g = phx_gravity_constant_wgs84_calc(lat_rad);
+
+    ts_profile.points().windows(2).map(|pair| {
+
+        let (top, bottom) = (pair[0], pair[1]);
+        let p_top = phx_pressure_by_depth_unesco_calc(top.z, p0, lat_rad, 0.0);
+        let p_bottom = phx_pressure_by_depth_unesco_calc(bottom.z, p0, lat_rad, 0.0);
+        let p_mid = 0.5 * (p_top + p_bottom);
+
+        let rho_top = phx_potential_density_calc(top.t, p_top, top.s, p_mid);
+        let rho_bottom = phx_potential_density_calc(bottom.t, p_bottom, bottom.s, p_mid);
+
+        BuoyancyLayer {
+            z_top: top.z,
+            z_bottom: bottom.z,
+            n2: 2.0 * g * (rho_bottom - rho_top) / ((rho_bottom + rho_top) * (bottom.z - top.z)),
+        }
+    }).collect()
+}
+
+// Summarizes the stability of a water column
+// layers - layers of the water column, see phx_buoyancy_frequency_ts_profile, at least one
+pub fn phx_stability_summary(layers: &[BuoyancyLayer]) -> StabilitySummary {
+
+    let mut summary = StabilitySummary {
+        n2_max: f64::NEG_INFINITY,
+        z_n2_max: f64::NAN,
+        n2_mean: 0.0,
+        unstable_count: 0,
+        unstable_thickness: 0.0,
+    };
+
+    let mut thickness = 0.0;
+
+    for layer in layers {
+
+        if layer.n2 > summary.n2_max {
+            summary.n2_max = layer.n2;
+            summary.z_n2_max = layer.z_mid();
+        }
+
+        if layer.is_unstable() {
+            summary.unstable_count += 1;
+            summary.unstable_thickness += layer.thickness();
+        }
+
+        summary.n2_mean += layer.n2 * layer.thickness();
+        thickness += layer.thickness();
+    }
+
+    summary.n2_mean /= thickness;
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::assert_approx_eq;
+    use crate::thermo::phx_thermal_expansion_calc;
+    use crate::unesco::phx_potential_temperature_calc;
+    use crate::PHX_ATM_PRESSURE_MBAR;
+
+    #[test]
+    fn phx_buoyancy_frequency_ts_profile_test() {
+
+        let lat_rad = 45.0f64.to_radians();
+        let g = phx_gravity_constant_wgs84_calc(lat_rad);
+
+        // thermocline in fresh water: N² = g * alpha * dT / dz
+        let profile = TsProfile::from_tuples(&[ (0.0, 20.0, 0.0), (10.0, 20.0, 0.0), (20.0, 15.0, 0.0), (30.0, 15.0, 0.0) ]).unwrap();
+        let layers = phx_buoyancy_frequency_ts_profile(&profile, PHX_ATM_PRESSURE_MBAR, lat_rad);
+
+        assert_eq!(layers.len(), 3);
+        assert_approx_eq!(layers[1].n2, g * phx_thermal_expansion_calc(17.5, PHX_ATM_PRESSURE_MBAR, 0.0) * 5.0 / 10.0, 1E-5);
+        // isothermal layer is slightly stable due to the adiabatic heating
+        assert!(layers[0].n2 > 0.0 && layers[0].n2 < 1E-6);
+        assert_approx_eq!(layers[1].z_mid(), 15.0, 1E-12);
+        assert!(layers.iter().all(|l| !l.is_unstable()));
+
+        let summary = phx_stability_summary(&layers);
+        assert!(summary.is_stable());
+        assert_approx_eq!(summary.n2_max, layers[1].n2, 1E-12);
+        assert_approx_eq!(summary.z_n2_max, 15.0, 1E-12);
+        assert_approx_eq!(summary.n2_mean, (layers[0].n2 + layers[1].n2 + layers[2].n2) / 3.0, 1E-12);
+    }
+
+    #[test]
+    fn phx_stability_summary_test() {
+
+        let lat_rad = 60.0f64.to_radians();
+
+        // deep water with constant potential temperature is neutral,
+        // while constant in situ temperature is slightly stable
+        let theta = 2.0;
+        let t_deep = theta + (theta - phx_potential_temperature_calc(theta, 4E5, 34.9, 0.0));
+        let neutral = TsProfile::from_tuples(&[ (0.0, theta, 34.9), (4000.0, t_deep, 34.9) ]).unwrap();
+        let layers = phx_buoyancy_frequency_ts_profile(&neutral, 0.0, lat_rad);
+        assert_approx_eq!(layers[0].n2, 0.0, 1E-8);
+
+        let isothermal = TsProfile::from_tuples(&[ (0.0, theta, 34.9), (4000.0, theta, 34.9) ]).unwrap();
+        assert!(phx_buoyancy_frequency_ts_profile(&isothermal, 0.0, lat_rad)[0].n2 > 0.0);
+
+        // warmer and saltier water under a fresher surface layer, then a static instability
+        let profile = TsProfile::from_tuples(&[ (0.0, 5.0, 30.0), (20.0, 5.0, 30.0), (40.0, 8.0, 34.0),
+                                                (60.0, 10.0, 34.0), (80.0, 6.0, 34.2) ]).unwrap();
+        let layers = phx_buoyancy_frequency_ts_profile(&profile, PHX_ATM_PRESSURE_MBAR, lat_rad);
+        let summary = phx_stability_summary(&layers);
+
+        assert!(layers[1].n2 > 1E-3);
+        assert!(layers[2].is_unstable());
+        assert_eq!(layers[2].n(), 0.0);
+        assert!(!summary.is_stable());
+        assert_eq!(summary.unstable_count, 1);
+        assert_approx_eq!(summary.unstable_thickness, 20.0, 1E-12);
+        assert_approx_eq!(summary.z_n2_max, 30.0, 1E-12);
+    }
+}
@@ -0,0 +1,328 @@
+// TEOS-10, the International Thermodynamic Equation Of Seawater - 2010
+// based on the 75-term polynomial expression for specific volume:
+// Roquet, F., Madec, G., McDougall, T. J., Barker, P. M., 2015.
+// Accurate polynomial expressions for the density and specific volume of seawater
+// using the TEOS-10 standard. Ocean Modelling, 90, pp. 29-43.
+// and the GSW Oceanographic Toolbox (www.teos-10.org)
+//
+// Unlike TEOS-10 itself, pressures are specified in mBar, as everywhere in the crate
+// sa - Absolute Salinity, g/kg
+// ct - Conservative Temperature, °C
+
+use crate::unesco::phx_potential_temperature_calc;
+
+pub const PHX_TEOS10_SSO: f64 = 35.16504;                  // Standard Ocean Reference Salinity, g/kg
+pub const PHX_TEOS10_UPS: f64 = PHX_TEOS10_SSO / 35.0;     // Unit conversion factor for salinities, g/kg
+pub const PHX_TEOS10_CP0: f64 = 3991.86795711963;           // Heat capacity used to define Conservative Temperature, J/(kg*K)
+
+const PHX_TEOS10_SFAC: f64   = 0.0248826675584615;        // 1 / (40 * ups)
+const PHX_TEOS10_OFFSET: f64 = 5.971840214030754E-1;      // deltaS = 24, offset = deltaS * sfac
+
+// (ys power, xs power, z power, coefficient) of the specific volume polynomial, m^3/kg
+const PHX_TEOS10_V: [(i32, i32, i32, f64); 75] = [
+    (0, 0, 0, 1.0769995862E-3),
+    (0, 0, 1, -6.0799143809E-5),
+    (0, 0, 2, 9.9856169219E-6),
+    (0, 0, 3, -1.1309361437E-6),
+    (0, 0, 4, 1.0531153080E-7),
+    (0, 0, 5, -1.2647261286E-8),
+    (0, 0, 6, 1.9613503930E-9),
+    (0, 1, 0, -3.1038981976E-4),
+    (0, 1, 1, 2.4262468747E-5),
+    (0, 1, 2, -5.8484432984E-7),
+    (0, 1, 3, 3.6310188515E-7),
+    (0, 1, 4, -1.1147125423E-7),
+    (0, 2, 0, 6.6928067038E-4),
+    (0, 2, 1, -3.4792460974E-5),
+    (0, 2, 2, -4.8122251597E-6),
+    (0, 2, 3, 1.6746303780E-8),
+    (0, 3, 0, -8.5047933937E-4),
+    (0, 3, 1, 3.7470777305E-5),
+    (0, 3, 2, 4.9263106998E-6),
+    (0, 4, 0, 5.8086069943E-4),
+    (0, 4, 1, -1.7322218612E-5),
+    (0, 4, 2, -1.7811974727E-6),
+    (0, 5, 0, -2.1092370507E-4),
+    (0, 5, 1, 3.0927427253E-6),
+    (0, 6, 0, 3.1932457305E-5),
+    (1, 0, 0, -1.5649734675E-5),
+    (1, 0, 1, 1.8505765429E-5),
+    (1, 0, 2, -1.1736386731E-6),
+    (1, 0, 3, -3.6527006553E-7),
+    (1, 0, 4, 3.1454099902E-7),
+    (1, 1, 0, 3.5009599764E-5),
+    (1, 1, 1, -9.5677088156E-6),
+    (1, 1, 2, -5.5699154557E-6),
+    (1, 1, 3, -2.7295696237E-7),
+    (1, 2, 0, -4.3592678561E-5),
+    (1, 2, 1, 1.1100834765E-5),
+    (1, 2, 2, 5.4620748834E-6),
+    (1, 3, 0, 3.4532461828E-5),
+    (1, 3, 1, -9.8447117844E-6),
+    (1, 3, 2, -1.3544185627E-6),
+    (1, 4, 0, -1.1959409788E-5),
+    (1, 4, 1, 2.5909225260E-6),
+    (1, 5, 0, 1.3864594581E-6),
+    (2, 0, 0, 2.7762106484E-5),
+    (2, 0, 1, -1.1716606853E-5),
+    (2, 0, 2, 2.1305028740E-6),
+    (2, 0, 3, 2.8695905159E-7),
+    (2, 1, 0, -3.7435842344E-5),
+    (2, 1, 1, -2.3678308361E-7),
+    (2, 1, 2, 3.9137387080E-7),
+    (2, 2, 0, 3.5907822760E-5),
+    (2, 2, 1, 2.9283346295E-6),
+    (2, 2, 2, -6.5731104067E-7),
+    (2, 3, 0, -1.8698584187E-5),
+    (2, 3, 1, -4.8826139200E-7),
+    (2, 4, 0, 3.8595339244E-6),
+    (3, 0, 0, -1.6521159259E-5),
+    (3, 0, 1, 7.9279656173E-6),
+    (3, 0, 2, -4.6132540037E-7),
+    (3, 1, 0, 2.4141479483E-5),
+    (3, 1, 1, -3.4558773655E-6),
+    (3, 1, 2, 7.7618888092E-9),
+    (3, 2, 0, -1.4353633048E-5),
+    (3, 2, 1, 3.1655306078E-7),
+    (3, 3, 0, 2.2863324556E-6),
+    (4, 0, 0, 6.9111322702E-6),
+    (4, 0, 1, -3.4102187482E-6),
+    (4, 0, 2, -6.3352916514E-8),
+    (4, 1, 0, -8.7595873154E-6),
+    (4, 1, 1, 1.2956717783E-6),
+    (4, 2, 0, 4.3703680598E-6),
+    (5, 0, 0, -8.0539615540E-7),
+    (5, 0, 1, 5.0736766814E-7),
+    (5, 1, 0, -3.3052758900E-7),
+    (6, 0, 0, 2.0543094268E-7),
+];
+
+// Reduced variables of the polynomial: xs (salinity), ys (temperature), z (pressure)
+fn phx_teos10_reduced(sa: f64, ct: f64, p: f64) -> (f64, f64, f64) {
+    ((PHX_TEOS10_SFAC * sa.max(0.0) + PHX_TEOS10_OFFSET).sqrt(), ct * 0.025, p * 1E-6)
+}
+
+// Calculates the specific volume (m^3/kg)
+// sa - Absolute Salinity, g/kg
+// ct - Conservative Temperature, °C
+// p - pressure, mBar
+pub fn phx_teos10_specvol_calc(sa: f64, ct: f64, p: f64) -> f64 {
+
+    let (xs, ys, z) = phx_teos10_reduced(sa, ct, p);
+
+    PHX_TEOS10_V.iter()
+        .map(|&(i, j, k, v)| v * ys.powi(i) * xs.powi(j) * z.powi(k))
+        .sum()
+}
+
+// Calculates the in situ density (kg/m^3)
+// sa - Absolute Salinity, g/kg
+// ct - Conservative Temperature, °C
+// p - pressure, mBar
+pub fn phx_teos10_density_calc(sa: f64, ct: f64, p: f64) -> f64 {
+    1.0 / phx_teos10_specvol_calc(sa, ct, p)
+}
+
+// Calculates the speed of sound in seawater (m/s)
+// sa - Absolute Salinity, g/kg
+// ct - Conservative Temperature, °C
+// p - pressure, mBar
+pub fn phx_teos10_sound_speed_calc(sa: f64, ct: f64, p: f64) -> f64 {
+
+    let (xs, ys, z) = phx_teos10_reduced(sa, ct, p);
+
+    // derivative of the specific volume with respect to z
+    let v_z: f64 = PHX_TEOS10_V.iter()
+        .filter(|&&(_, _, k, _)| k > 0)
+        .map(|&(i, j, k, v)| v * f64::from(k) * ys.powi(i) * xs.powi(j) * z.powi(k - 1))
+        .sum();
+
+    // z = p / 10^4 dbar, 1 dbar = 10^4 Pa
+    1.0E4 * phx_teos10_specvol_calc(sa, ct, p) / (-v_z).sqrt()
+}
+
+// Calculates Absolute Salinity (g/kg) from Practical Salinity
+// sp - Practical Salinity, PSU
+// dsa - Absolute Salinity Anomaly, g/kg, 0 for the seawater of Reference Composition
+// (i.e. Reference Salinity), the global atlas of anomalies is not included in the crate
+pub fn phx_teos10_sa_from_sp_calc(sp: f64, dsa: f64) -> f64 {
+    PHX_TEOS10_UPS * sp + dsa
+}
+
+// Calculates Conservative Temperature (°C) from potential temperature
+// referenced to the sea surface
+// sa - Absolute Salinity, g/kg
+// pt - potential temperature, °C
+pub fn phx_teos10_ct_from_pt_calc(sa: f64, pt: f64) -> f64 {
+
+    let x2 = PHX_TEOS10_SFAC * sa.max(0.0);
+    let x = x2.sqrt();
+    let y = pt * 0.025;
+
+    // potential enthalpy, J/kg
+    let pot_enthalpy = 61.01362420681071 + y * (168776.46138048015 +
+        y * (-2735.2785605119625 + y * (2574.2164453821433 +
+        y * (-1536.6644434977543 + y * (545.7340497931629 +
+        (-50.91091728474331 - 18.30489878927802 * y) * y))))) +
+        x2 * (268.5520265845071 + y * (-12019.028203559312 +
+        y * (3734.858026725145 + y * (-2046.7671145057618 +
+        y * (465.28655623826234 + (-0.6370820302376359 -
+        10.650848542359153 * y) * y)))) +
+        x * (937.2099110620707 + y * (588.1802812170108 +
+        y * (248.39476522971285 + (-3.871557904936333 -
+        2.6268019854268356 * y) * y)) +
+        x * (-1687.914374187449 + x * (246.9598888781377 +
+        x * (123.59576582457964 - 48.5891069025409 * x)) +
+        y * (936.3206544460336 +
+        y * (-942.7827304544439 + y * (369.4389437509002 +
+        (-33.83664947895248 - 9.987880382780322 * y) * y))))));
+
+    pot_enthalpy / PHX_TEOS10_CP0
+}
+
+// Calculates Conservative Temperature (°C) from in situ temperature
+// sa - Absolute Salinity, g/kg
+// t - in situ temperature, °C
+// p - pressure, mBar
+// The potential temperature is obtained by phx_potential_temperature_calc (EOS-80) instead of
+// the TEOS-10 Gibbs function, so this is an approximation of gsw_CT_from_t: the difference
+// grows with pressure and is about 0.2 mK at 1000 dbar
+pub fn phx_teos10_ct_from_t_calc(sa: f64, t: f64, p: f64) -> f64 {
+    phx_teos10_ct_from_pt_calc(sa, phx_potential_temperature_calc(t, p, sa / PHX_TEOS10_UPS, 0.0))
+}
+
+// Calculates the in situ freezing temperature of seawater (°C)
+// sa - Absolute Salinity, g/kg
+// p - pressure, mBar
+// saturation_fraction - saturation fraction of dissolved air in seawater, 0..1
+// Polynomial of McDougall et al. 2014 (gsw_t_freezing_poly)
+pub fn phx_teos10_t_freezing_calc(sa: f64, p: f64, saturation_fraction: f64) -> f64 {
+
+    const C: [f64; 23] = [ 0.002519, -5.946302841607319, 4.136051661346983, -1.115150523403847E1,
+                           1.476878746184344E1, -1.088873263630961E1, 2.96101883964073, -7.433320943962606,
+                           -1.561578562479883, 4.073774363480365E-2, 1.158414435887717E-2, -4.122639292422863E-1,
+                           -1.12318691562826E-1, 5.715012685553502E-1, 2.021682115652684E-1, 4.140574258089767E-2,
+                           -6.034228641903586E-1, -1.205825928146808E-2, -2.812172968619369E-1, 1.87724447402375E-2,
+                           -1.204395563789007E-1, 2.349147739749606E-1, 2.748444541144219E-3 ];
+
+    let sa_r = sa.max(0.0) * 1.0E-2;
+    let x = sa_r.sqrt();
+    let p_r = p * 1.0E-6;
+
+    let tf = C[0] + sa_r * (C[1] + x * (C[2] + x * (C[3] + x * (C[4] + x * (C[5] + C[6] * x))))) +
+             p_r * (C[7] + p_r * (C[8] + C[9] * p_r)) +
+             sa_r * p_r * (C[10] + p_r * (C[12] + p_r * (C[15] + C[21] * sa_r)) +
+             sa_r * (C[13] + C[17] * p_r + C[19] * sa_r) +
+             x * (C[11] + p_r * (C[14] + C[18] * p_r) + sa_r * (C[16] + C[20] * p_r + C[22] * sa_r)));
+
+    // effect of the dissolved air
+    tf - saturation_fraction * 1.0E-3 * (2.4 - sa / (2.0 * PHX_TEOS10_SSO))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn phx_teos10_standard_ocean_test() {
+        // Reference values for the Standard Ocean (SA = 35.16504 g/kg, t = 0 °C, p = 0)
+        // IAPWS, 2008. Release on the IAPWS Formulation 2008 for the Thermodynamic
+        // Properties of Seawater. Table 8a
+        // by definition, Conservative Temperature of the Standard Ocean is 0 °C
+
+        assert_approx_eq!(phx_teos10_ct_from_pt_calc(PHX_TEOS10_SSO, 0.0), 0.0, 1E-6);
+        assert_approx_eq!(phx_teos10_ct_from_t_calc(PHX_TEOS10_SSO, 0.0, 0.0), 0.0, 1E-6);
+        assert_approx_eq!(phx_teos10_density_calc(PHX_TEOS10_SSO, 0.0, 0.0), 1028.10720, 2E-3);
+        assert_approx_eq!(phx_teos10_specvol_calc(PHX_TEOS10_SSO, 0.0, 0.0), 9.72661217E-4, 2E-9);
+        assert_approx_eq!(phx_teos10_sound_speed_calc(PHX_TEOS10_SSO, 0.0, 0.0), 1449.00246, 1E-2);
+        assert_approx_eq!(phx_teos10_sa_from_sp_calc(35.0, 0.0), PHX_TEOS10_SSO, 1E-12);
+
+        // freezing point of air-free pure water at the sea surface
+        assert_approx_eq!(phx_teos10_t_freezing_calc(0.0, 0.0, 0.0), 0.002519, 1E-6);
+    }
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn phx_teos10_gsw_check_test() {
+        // Example cast of the GSW Oceanographic Toolbox (help of gsw_pt_from_t, gsw_CT_from_t,
+        // gsw_specvol and gsw_t_freezing), www.teos-10.org
+
+        let sa: [f64; 6] = [ 34.7118, 34.8915, 35.0256, 34.8472, 34.7366, 34.7324 ];
+        let t: [f64; 6] = [ 28.7856, 28.4329, 22.8103, 10.2600, 6.8863, 4.4036 ];
+        let p_dbar: [f64; 6] = [ 10.0, 50.0, 125.0, 250.0, 600.0, 1000.0 ];
+
+        // gsw_pt_from_t(SA, t, p, 0)
+        let pt: [f64; 6] = [ 28.783196819670632, 28.420983342398962, 22.784930399117108,
+                             10.230523661095731, 6.829230224409661, 4.324510571845719 ];
+        // gsw_CT_from_t(SA, t, p)
+        let ct: [f64; 6] = [ 28.809919826700281, 28.439227816091140, 22.786176893078498,
+                             10.226189266620782, 6.827213633479988, 4.323575748610455 ];
+        // gsw_t_freezing(SA, p, 1)
+        let t_freezing: [f64; 6] = [ -1.902730710149803, -1.942908619287183, -2.006861069199743,
+                                     -2.090985086875259, -2.351293130244222, -2.660498431598798 ];
+
+        for i in 0..sa.len() {
+            let p = p_dbar[i] * 100.0;
+
+            assert_approx_eq!(phx_teos10_ct_from_pt_calc(sa[i], pt[i]), ct[i], 1E-10 * ct[i]);
+            // the EOS-80 potential temperature makes phx_teos10_ct_from_t_calc an approximation
+            assert_approx_eq!(phx_teos10_ct_from_t_calc(sa[i], t[i], p), ct[i], 3E-4);
+            // the polynomial approximates the exact freezing temperature
+            assert_approx_eq!(phx_teos10_t_freezing_calc(sa[i], p, 1.0), t_freezing[i], 5E-4);
+
+            // sound speed is the pressure derivative of the specific volume: c^2 = -v^2 / (dv/dP)
+            let v = phx_teos10_specvol_calc(sa[i], ct[i], p);
+            let v_p = (phx_teos10_specvol_calc(sa[i], ct[i], p + 10.0) - phx_teos10_specvol_calc(sa[i], ct[i], p - 10.0)) / 2000.0;
+            assert_approx_eq!(phx_teos10_sound_speed_calc(sa[i], ct[i], p), v / (-v_p).sqrt(), 1E-5);
+        }
+
+        // gsw_specvol(SA, CT, p) at the first point of the cast, CT = 28.8099 °C
+        let specvol = 0.978626852431313E-3;
+        assert_approx_eq!(phx_teos10_specvol_calc(sa[0], 28.8099, 1000.0), specvol, 1E-10 * specvol);
+        assert_approx_eq!(phx_teos10_density_calc(sa[0], 28.8099, 1000.0), 1.0 / specvol, 1E-10 / specvol);
+
+        // Reference Salinity of the Standard Ocean: gsw_SR_from_SP(35)
+        assert_approx_eq!(phx_teos10_sa_from_sp_calc(35.0, 0.0), 35.16504, 1E-10 * 35.16504);
+    }
+
+    #[test]
+    fn phx_teos10_vs_eos80_test() {
+
+        let ref_salinity: [f64; 5] = [ 0.0, 10.0, 20.0, 30.0, 35.0 ];
+        let ref_temperature: [f64; 5] = [ 0.0, 5.0, 10.0, 20.0, 30.0 ];
+
+        for &sp in ref_salinity.iter() {
+
+            let sa = phx_teos10_sa_from_sp_calc(sp, 0.0);
+
+            // freezing point according to UNESCO 1983 up to 500 dbar
+            for p_idx in 0..6 {
+                let p = p_idx as f64 * 1E4;
+                assert_approx_eq!(phx_teos10_t_freezing_calc(sa, p, 1.0), phx_water_fpoint_calc(p, sp), 5E-3);
+            }
+
+            for &t in ref_temperature.iter() {
+                assert_approx_eq!(EquationOfState::Teos10.density_calc(t, 0.0, sp), phx_water_density_calc(t, 0.0, sp), 2E-2);
+                assert_approx_eq!(EquationOfState::Teos10.density_calc(t, 1E5, sp), phx_water_density_calc(t, 1E5, sp), 5E-2);
+            }
+        }
+
+        for &t in ref_temperature.iter() {
+            let ct = phx_teos10_ct_from_t_calc(PHX_TEOS10_SSO, t, 0.0);
+            assert_approx_eq!(phx_teos10_sound_speed_calc(PHX_TEOS10_SSO, ct, 0.0), phx_speed_of_sound_unesco_calc(t, 0.0, 35.0), 0.3);
+        }
+    }
+
+    #[test]
+    fn phx_teos10_ts_profile_test() {
+
+        let ts_profile = TsProfile::from_tuples(&[ (0.0, 25.0, 35.0), (200.0, 12.0, 35.0), (1500.0, 4.0, 34.7) ]).unwrap();
+
+        let h_eos80 = phx_try_depth_by_pressure_ts_profile(1E5, PHX_ATM_PRESSURE_MBAR, PHX_GRAVITY_ACC_MPS2, &ts_profile, 1000, EquationOfState::Eos80).unwrap();
+        let h_teos10 = phx_try_depth_by_pressure_ts_profile(1E5, PHX_ATM_PRESSURE_MBAR, PHX_GRAVITY_ACC_MPS2, &ts_profile, 1000, EquationOfState::Teos10).unwrap();
+
+        assert_approx_eq!(h_eos80, h_teos10, 0.05);
+    }
+}
@@ -0,0 +1,160 @@
+// Thermodynamic properties of seawater consistent with phx_water_density_calc (EOS-80)
+
+use crate::{phx_eos80_terms_calc, Eos80Terms};
+
+// Calculates the specific heat capacity of seawater at constant pressure, J/(kg*°C)
+// Millero, F. J., Perron, G., Desnoyers, J. E. (1973). Heat capacity of seawater solutions
+// from 5 to 35 °C and 0.5 to 22 ‰ chlorinity. J. Geophys. Res., 78, 4499-4506
+// Unesco technical papers in marine science vol. 44, 1983, pp. 31-33
+// t - temperature, °C
+// p - pressure, mBar
+// s - salinity, PSU
+pub fn phx_specific_heat_calc(t: f64, p: f64, s: f64) -> f64 {
+
+    let p = p / 1000.0;
+    let sr = s.abs().sqrt();
+    let s3_2 = s * sr;
+
+    // at zero pressure
+    let cp_st0 = (((2.093236E-5 * t - 2.654387E-3) * t + 1.412855E-1) * t - 3.720283) * t + 4217.4 +
+                 ((-1.38385E-3 * t + 0.1072763) * t - 7.64357) * s +
+                 ((5.148E-5 * t - 4.07718E-3) * t + 0.1770383) * s3_2;
+
+    // pressure correction for fresh water
+    let d_cp0_tp = ((((6.136E-13 * t - 6.5637E-11) * t + 2.6380E-9) * t - 5.422E-8) * p +
+                    ((((2.2956E-11 * t - 4.0027E-9) * t + 2.87533E-7) * t - 1.08645E-5) * t + 2.4931E-4)) * p * p +
+                   ((((1.7168E-8 * t + 2.0357E-6) * t - 3.13885E-4) * t + 1.45747E-2) * t - 4.9592E-1) * p;
+
+    // pressure correction for salinity
+    let d_cp_stp = (((((-2.9179E-10 * t + 2.5941E-8) * t + 9.802E-7) * t - 1.28315E-4) * t + 4.9247E-3) * s +
+                    ((3.122E-8 * t - 1.517E-6) * t - 1.2331E-4) * s3_2) * p +
+                   ((((1.8448E-11 * t - 2.3905E-9) * t + 1.17054E-7) * t - 2.9558E-6) * s + 9.971E-8 * s3_2) * p * p +
+                   (((3.513E-13 * t - 1.7682E-11) * t + 5.540E-10) * s - 1.4300E-12 * t * s3_2) * p * p * p;
+
+    cp_st0 + d_cp0_tp + d_cp_stp
+}
+
+// Partial derivatives of the logarithm of the EOS-80 density, rho = rho0 * K / (K - p),
+// where rho0 is the one-atmosphere density and K is the secant bulk modulus:
+// d ln(rho) = d ln(rho0) - p / (K * (K - p)) * dK + (K - p * dK/dp) / (K * (K - p)) * dp
+// The terms and their derivatives are shared with phx_water_density_calc, see phx_eos80_terms_calc
+// t - temperature, °C
+// p - pressure, mBar
+// s - salinity, PSU
+// Returns (d/dt, 1/°C; d/ds, 1/PSU; d/dp, 1/bar)
+fn phx_density_log_derivatives(t: f64, p: f64, s: f64) -> (f64, f64, f64) {
+
+    let p = p / 1000.0;
+    let Eos80Terms { sig: (sig, sig_t, sig_s), k0: (k0, k0_t, k0_s), a: (a, a_t, a_s), b: (b, b_t, b_s) } =
+        phx_eos80_terms_calc(t, s.max(0.0));
+
+    let k = (b * p + a) * p + k0;
+    let k_t = (b_t * p + a_t) * p + k0_t;
+    let k_s = (b_s * p + a_s) * p + k0_s;
+    let k_p = 2.0 * b * p + a;
+
+    let rho0 = 1000.0 + sig;
+    let kkp = k * (k - p);
+
+    (sig_t / rho0 - p * k_t / kkp,
+     sig_s / rho0 - p * k_s / kkp,
+     (k - p * k_p) / kkp)
+}
+
+// Calculates the thermal expansion coefficient of seawater, 1/°C
+// alpha = -(1/rho) * (d rho / d t) at constant salinity and pressure
+// t - temperature, °C
+// p - pressure, mBar
+// s - salinity, PSU
+pub fn phx_thermal_expansion_calc(t: f64, p: f64, s: f64) -> f64 {
+    -phx_density_log_derivatives(t, p, s).0
+}
+
+// Calculates the haline contraction coefficient of seawater, 1/PSU
+// beta = (1/rho) * (d rho / d s) at constant temperature and pressure
+// t - temperature, °C
+// p - pressure, mBar
+// s - salinity, PSU
+pub fn phx_haline_contraction_calc(t: f64, p: f64, s: f64) -> f64 {
+    phx_density_log_derivatives(t, p, s).1
+}
+
+// Calculates the isothermal compressibility of seawater, 1/mBar
+// kappa = (1/rho) * (d rho / d p) at constant temperature and salinity
+// t - temperature, °C
+// p - pressure, mBar
+// s - salinity, PSU
+pub fn phx_isothermal_compressibility_calc(t: f64, p: f64, s: f64) -> f64 {
+    phx_density_log_derivatives(t, p, s).2 / 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{phx_secant_bulk_modulus_calc, phx_water_density_calc};
+    use crate::assert_approx_eq;
+
+    #[test]
+    fn phx_specific_heat_calc_test() {
+        // Reference value according to
+        // Algorithms for computation of fundamental properties of seawater.
+        // Unesco technical papers in marine science vol. 44, 1983, pp. 33
+        assert_approx_eq!(phx_specific_heat_calc(40.0, 1E6, 40.0), 3849.500, 1E-3);
+
+        // fresh water at atmospheric pressure, J/(kg*°C)
+        assert_approx_eq!(phx_specific_heat_calc(20.0, 0.0, 0.0), 4182.0, 1.0);
+    }
+
+    #[test]
+    fn phx_secant_bulk_modulus_calc_test() {
+        // Check values of the equation of state, Millero et al. 1980
+        // Unesco technical papers in marine science vol. 44, 1983, pp. 19
+        assert_approx_eq!(phx_secant_bulk_modulus_calc(5.0, 0.0, 0.0) / 1000.0, 20337.80, 1E-2);
+        assert_approx_eq!(phx_secant_bulk_modulus_calc(5.0, 0.0, 35.0) / 1000.0, 22185.93, 1E-2);
+        assert_approx_eq!(phx_secant_bulk_modulus_calc(25.0, 1E6, 35.0) / 1000.0, 27108.95, 1E-2);
+        assert_approx_eq!(phx_water_density_calc(25.0, 1E6, 35.0), 1062.53817, 1E-5);
+    }
+
+    #[test]
+    fn phx_expansion_contraction_calc_test() {
+
+        // fresh water has its maximum density at 3.98 °C
+        assert!(phx_thermal_expansion_calc(3.9, 0.0, 0.0) < 0.0);
+        assert!(phx_thermal_expansion_calc(4.1, 0.0, 0.0) > 0.0);
+
+        // fresh water at 20 °C: alpha = 2.07E-4 1/°C, kappa = 4.59E-10 1/Pa
+        assert_approx_eq!(phx_thermal_expansion_calc(20.0, 0.0, 0.0), 2.07E-4, 1E-6);
+        assert_approx_eq!(phx_isothermal_compressibility_calc(20.0, 0.0, 0.0), 4.59E-8, 0.05E-8);
+
+        // seawater: beta is about 7.6E-4 1/PSU and decreases with temperature
+        let beta = phx_haline_contraction_calc(10.0, 0.0, 35.0);
+        assert_approx_eq!(beta, 7.6E-4, 0.1E-4);
+        assert!(phx_haline_contraction_calc(25.0, 0.0, 35.0) < beta);
+        assert!(phx_haline_contraction_calc(10.0, 0.0, 0.0) > 0.0);
+
+        // compressibility from the bulk modulus at zero pressure: 1/K
+        assert_approx_eq!(phx_isothermal_compressibility_calc(10.0, 0.0, 35.0) * phx_secant_bulk_modulus_calc(10.0, 0.0, 35.0), 1.0, 1E-12);
+    }
+
+    #[test]
+    fn phx_density_derivatives_vs_finite_differences_test() {
+
+        // the analytic derivatives agree with central differences of phx_water_density_calc
+        let (dt, ds, dp) = (1E-3, 1E-3, 1E1);
+
+        for &(t, p, s) in [ (0.0, 0.0, 35.0), (4.0, 0.0, 0.0), (10.0, 2E5, 35.0), (25.0, 1E6, 35.0), (30.0, 5E5, 40.0), (2.0, 6E5, 34.7) ].iter() {
+
+            let rho = phx_water_density_calc(t, p, s);
+            let alpha = -(phx_water_density_calc(t + dt, p, s) - phx_water_density_calc(t - dt, p, s)) / (2.0 * dt * rho);
+            let beta = (phx_water_density_calc(t, p, s + ds) - phx_water_density_calc(t, p, s - ds)) / (2.0 * ds * rho);
+            let kappa = (phx_water_density_calc(t, p + dp, s) - phx_water_density_calc(t, p - dp, s)) / (2.0 * dp * rho);
+
+            assert_approx_eq!(phx_thermal_expansion_calc(t, p, s), alpha, 1E-9);
+            assert_approx_eq!(phx_isothermal_compressibility_calc(t, p, s), kappa, 1E-13);
+            if s > 0.0 {
+                assert_approx_eq!(phx_haline_contraction_calc(t, p, s), beta, 1E-9);
+            }
+        }
+    }
+}
@@ -0,0 +1,187 @@
+// Transport properties of fresh water and seawater
+// Sharqawy, M. H., Lienhard, J. H., Zubair, S. M. (2010). Thermophysical properties of seawater:
+// a review of existing correlations and data. Desalination and Water Treatment, 16, 354-380
+// as adopted by ITTC – Recommended Procedures 7.5-02-01-03. Fresh Water and Seawater Properties
+// https://ittc.info/media/7503/75-02-01-03.pdf
+//
+// The correlations are given for the atmospheric pressure. The phx_try_* versions and
+// phx_kinematic_viscosity_calc take the pressure into account by the pressure terms of:
+// Kestin, J., Khalifa, H. E., Correia, R. J. (1981). Tables of the dynamic and kinematic
+// viscosity of aqueous NaCl solutions in the temperature range 20-150 °C and the pressure
+// range 0.1-35 MPa. J. Phys. Chem. Ref. Data, 10(1), 71-87 (viscosity)
+// Caldwell, D. R. (1974). Thermal conductivity of sea water.
+// Deep-Sea Research, 21(2), 131-137 (thermal conductivity)
+// The effect is below 1% for the upper 1000 m of the ocean, pressures
+// beyond PHX_TRANSPORT_MAX_PRESSURE_MBAR are rejected
+
+use crate::{phx_water_density_calc, PhxError, PHX_ATM_PRESSURE_MBAR};
+use crate::teos10::PHX_TEOS10_UPS;
+
+pub const PHX_TRANSPORT_MAX_PRESSURE_MBAR: f64 = PHX_ATM_PRESSURE_MBAR + 1E5; // absolute pressure at about 1000 m depth, mBar
+
+// Calculates dynamic viscosity of water, Pa*s
+// Sharqawy et al. (2010), eqs. 22, 23, valid for 0..180 °C, 0..150 g/kg
+// t - temperature, °C
+// s - salinity, PSU
+pub fn phx_dynamic_viscosity_calc(t: f64, s: f64) -> f64 {
+
+    // reference salinity, kg/kg
+    let sr = s * PHX_TEOS10_UPS / 1000.0;
+
+    let mu_w = 4.2844E-5 + 1.0 / (0.157 * (t + 64.993).powi(2) - 91.296);
+    let a = (-9.52E-5 * t + 1.998E-2) * t + 1.541;
+    let b = (4.724E-4 * t - 7.561E-2) * t + 7.974;
+
+    mu_w * (1.0 + (b * sr + a) * sr)
+}
+
+// Calculates dynamic viscosity of water at the given pressure, Pa*s, see phx_dynamic_viscosity_calc
+// t - temperature, °C
+// p - pressure, mBar, up to PHX_TRANSPORT_MAX_PRESSURE_MBAR
+// s - salinity, PSU
+pub fn phx_try_dynamic_viscosity_calc(t: f64, p: f64, s: f64) -> Result<f64, PhxError> {
+
+    phx_transport_check_pressure(p)?;
+    Ok(phx_dynamic_viscosity_calc(t, s) * phx_viscosity_pressure_factor(t, p))
+}
+
+// Relative change of viscosity with pressure, Kestin et al. (1981):
+// mu(p) = mu(0.1 MPa) * (1 + beta * p), beta in 1/GPa, p - gauge pressure, GPa
+fn phx_viscosity_pressure_factor(t: f64, p: f64) -> f64 {
+
+    let beta = (((-1.05E-8 * t + 4.47E-6) * t - 6.97E-4) * t + 5.74E-2) * t - 1.297;
+    1.0 + beta * (p - PHX_ATM_PRESSURE_MBAR) * 1E-7
+}
+
+// Calculates kinematic viscosity of water, m^2/s
+// t - temperature, °C
+// p - pressure, mBar
+// s - salinity, PSU
+pub fn phx_kinematic_viscosity_calc(t: f64, p: f64, s: f64) -> f64 {
+    phx_dynamic_viscosity_calc(t, s) * phx_viscosity_pressure_factor(t, p) / phx_water_density_calc(t, p, s)
+}
+
+// Calculates kinematic viscosity of water, m^2/s, see phx_kinematic_viscosity_calc
+// t - temperature, °C
+// p - pressure, mBar, up to PHX_TRANSPORT_MAX_PRESSURE_MBAR
+// s - salinity, PSU
+pub fn phx_try_kinematic_viscosity_calc(t: f64, p: f64, s: f64) -> Result<f64, PhxError> {
+
+    phx_transport_check_pressure(p)?;
+    Ok(phx_kinematic_viscosity_calc(t, p, s))
+}
+
+// Calculates thermal conductivity of water, W/(m*°C)
+// Jamieson, D. T., Tudhope, J. S. (1970). Physical properties of sea water solutions:
+// thermal conductivity. Desalination, 8, 393-401
+// Sharqawy et al. (2010), eq. 13, valid for 0..180 °C, 0..160 g/kg
+// t - temperature, °C
+// s - salinity, PSU
+pub fn phx_thermal_conductivity_calc(t: f64, s: f64) -> f64 {
+
+    // reference salinity, g/kg
+    let sr = s * PHX_TEOS10_UPS;
+    // IPTS-68 temperature, K
+    let tk = 1.00024 * t + 273.15;
+
+    let lg_k = (240.0 + 0.0002 * sr).log10() +
+               0.434 * (2.3 - (343.5 + 0.037 * sr) / tk) * (1.0 - tk / (647.0 + 0.03 * sr)).cbrt();
+
+    10.0f64.powf(lg_k) / 1000.0
+}
+
+// Calculates thermal conductivity of water at the given pressure, W/(m*°C), see phx_thermal_conductivity_calc
+// t - temperature, °C
+// p - pressure, mBar, up to PHX_TRANSPORT_MAX_PRESSURE_MBAR
+// s - salinity, PSU
+pub fn phx_try_thermal_conductivity_calc(t: f64, p: f64, s: f64) -> Result<f64, PhxError> {
+
+    phx_transport_check_pressure(p)?;
+
+    // Caldwell (1974): k = 0.5711 * (1 + 0.003 t - 1.025E-5 t^2 + 6.53E-4 p - 0.00029 s),
+    // p - gauge pressure, MPa, s - salinity, g/kg, only the relative pressure term is used
+    let sr = s * PHX_TEOS10_UPS;
+    let k0 = 1.0 + (0.003 - 1.025E-5 * t) * t - 0.00029 * sr;
+    Ok(phx_thermal_conductivity_calc(t, s) * (1.0 + 6.53E-4 * (p - PHX_ATM_PRESSURE_MBAR) * 1E-4 / k0))
+}
+
+fn phx_transport_check_pressure(p: f64) -> Result<(), PhxError> {
+
+    if !(0.0..=PHX_TRANSPORT_MAX_PRESSURE_MBAR).contains(&p) {
+        return Err(PhxError::PressureOutOfRange { value: p, min: 0.0, max: PHX_TRANSPORT_MAX_PRESSURE_MBAR });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    #[test]
+    fn phx_viscosity_calc_test() {
+
+        // Fresh water, ITTC 7.5-02-01-03, Table 1
+        let ref_temperatures: [f64; 6] = [ 10.0, 15.0, 20.0, 25.0, 30.0, 40.0 ];
+        let ref_mu: [f64; 6] = [ 1.3059E-3, 1.1375E-3, 1.0016E-3, 0.8900E-3, 0.7972E-3, 0.6527E-3 ];
+        let ref_nu: [f64; 6] = [ 1.3063E-6, 1.1386E-6, 1.0034E-6, 0.8927E-6, 0.8007E-6, 0.6579E-6 ];
+
+        for idx in 0..ref_temperatures.len() {
+            assert_approx_eq!(phx_dynamic_viscosity_calc(ref_temperatures[idx], 0.0), ref_mu[idx], 0.003E-3);
+            assert_approx_eq!(phx_kinematic_viscosity_calc(ref_temperatures[idx], PHX_ATM_PRESSURE_MBAR, 0.0), ref_nu[idx], 0.003E-6);
+        }
+
+        // Seawater, 35.16504 g/kg, ITTC 7.5-02-01-03, Table 2
+        let sw_temperatures: [f64; 5] = [ 10.0, 15.0, 20.0, 25.0, 30.0 ];
+        let sw_mu: [f64; 5] = [ 1.3972E-3, 1.2203E-3, 1.0770E-3, 0.9592E-3, 0.8610E-3 ];
+        let sw_nu: [f64; 5] = [ 1.3606E-6, 1.1894E-6, 1.0510E-6, 0.9373E-6, 0.8427E-6 ];
+        let sw_s = 35.16504 / PHX_TEOS10_UPS;
+
+        for idx in 0..sw_temperatures.len() {
+            assert_approx_eq!(phx_dynamic_viscosity_calc(sw_temperatures[idx], sw_s), sw_mu[idx], 0.003E-3);
+            assert_approx_eq!(phx_kinematic_viscosity_calc(sw_temperatures[idx], PHX_ATM_PRESSURE_MBAR, sw_s), sw_nu[idx], 0.003E-6);
+        }
+
+        // pressure lowers the viscosity of cold water by about 1% per 1000 m
+        assert_eq!(phx_try_dynamic_viscosity_calc(15.0, PHX_ATM_PRESSURE_MBAR, 35.0), Ok(phx_dynamic_viscosity_calc(15.0, 35.0)));
+        let mu_deep = phx_try_dynamic_viscosity_calc(5.0, PHX_TRANSPORT_MAX_PRESSURE_MBAR, 35.0).unwrap();
+        assert_approx_eq!(mu_deep / phx_dynamic_viscosity_calc(5.0, 35.0), 1.0 - 0.01, 0.002);
+        assert_approx_eq!(phx_try_kinematic_viscosity_calc(5.0, PHX_TRANSPORT_MAX_PRESSURE_MBAR, 35.0).unwrap(),
+                          mu_deep / phx_water_density_calc(5.0, PHX_TRANSPORT_MAX_PRESSURE_MBAR, 35.0), 1E-15);
+        assert!(phx_try_kinematic_viscosity_calc(15.0, PHX_TRANSPORT_MAX_PRESSURE_MBAR, 35.0).is_ok());
+        assert_eq!(phx_try_dynamic_viscosity_calc(15.0, 5E5, 35.0),
+                   Err(PhxError::PressureOutOfRange { value: 5E5, min: 0.0, max: PHX_TRANSPORT_MAX_PRESSURE_MBAR }));
+        assert!(phx_try_kinematic_viscosity_calc(15.0, f64::NAN, 35.0).is_err());
+
+        // seawater is more viscous than fresh water, viscosity decreases with temperature
+        assert!(phx_dynamic_viscosity_calc(15.0, 35.0) > phx_dynamic_viscosity_calc(15.0, 0.0));
+        assert!(phx_dynamic_viscosity_calc(20.0, 35.0) < phx_dynamic_viscosity_calc(15.0, 35.0));
+    }
+
+    #[test]
+    fn phx_thermal_conductivity_calc_test() {
+
+        // Fresh water, IAPWS 2011 at 0.1 MPa, the correlation deviates from it by less than 1%
+        assert_approx_eq!(phx_thermal_conductivity_calc(20.0, 0.0), 0.5984, 0.006);
+        assert_approx_eq!(phx_thermal_conductivity_calc(40.0, 0.0), 0.6306, 0.006);
+
+        // Seawater, 35.16504 g/kg, Sharqawy et al. (2010), eq. 13
+        let sw_s = 35.16504 / PHX_TEOS10_UPS;
+        let sw_temperatures: [f64; 5] = [ 0.0, 10.0, 20.0, 30.0, 40.0 ];
+        let sw_k: [f64; 5] = [ 0.5695, 0.5863, 0.6015, 0.6154, 0.6278 ];
+        for idx in 0..sw_temperatures.len() {
+            assert_approx_eq!(phx_thermal_conductivity_calc(sw_temperatures[idx], sw_s), sw_k[idx], 1E-4);
+        }
+
+        // Seawater conductivity is slightly lower
+        assert!(phx_thermal_conductivity_calc(20.0, 35.0) < phx_thermal_conductivity_calc(20.0, 0.0));
+
+        // and grows with pressure by about 0.65% per 1000 m
+        assert_eq!(phx_try_thermal_conductivity_calc(20.0, PHX_ATM_PRESSURE_MBAR, 35.0), Ok(phx_thermal_conductivity_calc(20.0, 35.0)));
+        let k_deep = phx_try_thermal_conductivity_calc(20.0, PHX_TRANSPORT_MAX_PRESSURE_MBAR, 35.0).unwrap();
+        assert_approx_eq!(k_deep / phx_thermal_conductivity_calc(20.0, 35.0), 1.0065, 0.0005);
+        assert!(phx_try_thermal_conductivity_calc(20.0, -1.0, 35.0).is_err());
+    }
+}
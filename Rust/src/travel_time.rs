@@ -0,0 +1,406 @@
+// Sound speed averaging and travel times along acoustic paths through a TS profile
+//
+// Sound speed at a depth is calculated by the temperature and salinity interpolated
+// from the TS profile and the pressure obtained from depth the same way
+// as in phx_try_vertical_sound_path_ts_profile
+
+use crate::raytrace::phx_find_eigenrays;
+use crate::{phx_check_step_count, phx_ts_profile_from_tuples, phx_ts_profile_layers_split, phx_ts_profile_pressure_calc};
+use crate::{EquationOfState, GravityModel, PhxError, SoundSpeedModel, SoundSpeedProfile, TsProfile};
+
+const PHX_HARMONIC_MEAN_STEPS: i32 = 1000;          // number of depth intervals for the slowness integration
+const PHX_SOUND_SPEED_LAYER_SUBDIVISIONS: usize = 4; // number of sound speed layers within a TS-profile layer
+
+// Sound speed at the given depth, m/s
+pub(crate) fn phx_ts_profile_speed(ts_profile: &TsProfile, z: f64, g: f64, eos: EquationOfState, model: SoundSpeedModel) -> f64 {
+    let p = ts_profile.interpolate(z);
+    model.speed_calc(p.t, phx_ts_profile_pressure_calc(ts_profile, p.z, GravityModel::Constant(g), eos), p.s, p.z)
+}
+
+// Checks that depths are within the TS profile
+fn phx_check_depths(ts_profile: &TsProfile, depths: &[f64]) -> Result<(), PhxError> {
+
+    let z_max = ts_profile.max_depth();
+    match depths.iter().find(|&&z| !(z >= 0.0 && z <= z_max)) {
+        Some(&z) => Err(PhxError::DepthOutOfRange { value: z, min: 0.0, max: z_max }),
+        None => Ok(()),
+    }
+}
+
+// Calculates the harmonic mean sound speed between two depths, m/s
+// c_h = (z2 - z1) / integral(dz / c(z))
+// The integration intervals are distributed over the TS-profile layers in proportion
+// to their thickness and do not cross the TS-profile points
+// z1, z2 - depths, m (in any order)
+// ts_profile - vertical Temperature-Salinity profile
+// g - gravity acceleration, m/s^2
+// eos - equation of state to calculate water density
+// model - sound speed equation
+// n_z - number of depth intervals for integration
+pub fn phx_try_harmonic_mean_sound_speed_ts_profile(z1: f64, z2: f64, ts_profile: &TsProfile, g: f64, eos: EquationOfState,
+                                                    model: SoundSpeedModel, n_z: i32) -> Result<f64, PhxError> {
+
+    phx_check_step_count(n_z)?;
+    phx_check_depths(ts_profile, &[ z1, z2 ])?;
+
+    if z1 == z2 {
+        return Ok(phx_ts_profile_speed(ts_profile, z1, g, eos, model));
+    }
+
+    // midpoint integration of slowness over each layer
+    let mut time = 0.0;
+    for (z_top, z_bottom, n) in phx_ts_profile_layers_split(ts_profile, z1, z2, n_z) {
+        let dz = (z_bottom - z_top) / (n as f64);
+        time += (0..n).map(|i| dz / phx_ts_profile_speed(ts_profile, z_top + (i as f64 + 0.5) * dz, g, eos, model)).sum::<f64>();
+    }
+
+    Ok((z2 - z1).abs() / time)
+}
+
+// Calculates the harmonic mean sound speed, see phx_try_harmonic_mean_sound_speed_ts_profile
+// ts_profile - as an array of (f64, f64, f64) ordered by depth
+// Panics if the arguments are invalid
+pub fn phx_harmonic_mean_sound_speed_ts_profile(z1: f64, z2: f64, g: f64, ts_profile: &[(f64, f64, f64)], n_z: i32) -> f64 {
+
+    match phx_try_harmonic_mean_sound_speed_ts_profile(z1, z2, &phx_ts_profile_from_tuples(ts_profile), g, EquationOfState::Eos80, SoundSpeedModel::ChenMillero, n_z) {
+        Ok(c) => c,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+// Calculates the effective sound speed along the direct path between a source and a receiver, m/s
+// i.e. the slant range divided by the travel time along the direct ray
+// z_src, z_rx - depths of the source and the receiver, m
+// r - horizontal range between the source and the receiver, m
+// ts_profile - vertical Temperature-Salinity profile
+// g - gravity acceleration, m/s^2
+// eos - equation of state to calculate water density
+// model - sound speed equation
+pub fn phx_try_effective_sound_speed_ts_profile(z_src: f64, z_rx: f64, r: f64, ts_profile: &TsProfile,
+                                                g: f64, eos: EquationOfState, model: SoundSpeedModel) -> Result<f64, PhxError> {
+
+    phx_check_depths(ts_profile, &[ z_src, z_rx ])?;
+
+    if r == 0.0 {
+        return phx_try_harmonic_mean_sound_speed_ts_profile(z_src, z_rx, ts_profile, g, eos, model, PHX_HARMONIC_MEAN_STEPS);
+    }
+
+    // the TS-profile layers are subdivided, as sound speed is not linear in temperature and salinity within them
+    let m = PHX_SOUND_SPEED_LAYER_SUBDIVISIONS;
+    let mut points = Vec::new();
+    for layer in ts_profile.points().windows(2) {
+        for k in 0..m {
+            let z = layer[0].z + (k as f64) * (layer[1].z - layer[0].z) / (m as f64);
+            points.push((z, phx_ts_profile_speed(ts_profile, z, g, eos, model)));
+        }
+    }
+    let z_max = ts_profile.max_depth();
+    points.push((z_max, phx_ts_profile_speed(ts_profile, z_max, g, eos, model)));
+
+    let ssp = SoundSpeedProfile::new(points)?;
+    let eigenrays = phx_find_eigenrays(&ssp, z_src, z_rx, r, 0)?;
+
+    match eigenrays.first() {
+        Some(direct) => Ok(r.hypot(z_rx - z_src) / direct.t),
+        None => Err(PhxError::NoDirectPath),
+    }
+}
+
+// Calculates the effective sound speed, see phx_try_effective_sound_speed_ts_profile
+// ts_profile - as an array of (f64, f64, f64) ordered by depth
+// Panics if the arguments are invalid
+pub fn phx_effective_sound_speed_ts_profile(z_src: f64, z_rx: f64, r: f64, g: f64, ts_profile: &[(f64, f64, f64)]) -> f64 {
+
+    match phx_try_effective_sound_speed_ts_profile(z_src, z_rx, r, &phx_ts_profile_from_tuples(ts_profile), g, EquationOfState::Eos80, SoundSpeedModel::ChenMillero) {
+        Ok(c) => c,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+/// Direction of the vertical sound propagation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerticalDirection {
+    /// towards the bottom, depth increases
+    Downward,
+    /// towards the water surface, depth decreases
+    Upward,
+}
+
+// Calculates the time of flight of sound in vertical direction between two depths, sec
+// z1, z2 - depths, m (the source may be either above or below the receiver)
+// n_z - number of depth intervals for integration
+// ts_profile - vertical Temperature-Salinity profile
+// g - gravity acceleration, m/s^2
+// eos - equation of state to calculate water density
+// model - sound speed equation
+pub fn phx_try_vertical_travel_time_ts_profile(z1: f64, z2: f64, n_z: i32, ts_profile: &TsProfile,
+                                               g: f64, eos: EquationOfState, model: SoundSpeedModel) -> Result<f64, PhxError> {
+
+    let c_h = phx_try_harmonic_mean_sound_speed_ts_profile(z1, z2, ts_profile, g, eos, model, n_z)?;
+    Ok((z2 - z1).abs() / c_h)
+}
+
+// Calculates the vertical time of flight, see phx_try_vertical_travel_time_ts_profile
+// ts_profile - as an array of (f64, f64, f64) ordered by depth
+// Panics if the arguments are invalid
+pub fn phx_vertical_travel_time_ts_profile(z1: f64, z2: f64, n_z: i32, g: f64, ts_profile: &[(f64, f64, f64)]) -> f64 {
+
+    match phx_try_vertical_travel_time_ts_profile(z1, z2, n_z, &phx_ts_profile_from_tuples(ts_profile), g, EquationOfState::Eos80, SoundSpeedModel::ChenMillero) {
+        Ok(t) => t,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+// Calculates the depth, which sound traveling in vertical direction from a given depth
+// reaches during a given time of flight, the inverse of phx_try_vertical_travel_time_ts_profile
+// z0 - initial depth, m
+// tof - time of flight, sec
+// direction - direction of the propagation
+// n_t - number of time intervals for integration
+// ts_profile - vertical Temperature-Salinity profile
+// g - gravity acceleration, m/s^2
+// eos - equation of state to calculate water density
+// model - sound speed equation
+// Returns an error if sound reaches the surface or the deepest point of the profile earlier
+#[allow(clippy::too_many_arguments)]
+pub fn phx_try_depth_by_travel_time_ts_profile(z0: f64, tof: f64, direction: VerticalDirection, n_t: i32, ts_profile: &TsProfile,
+                                               g: f64, eos: EquationOfState, model: SoundSpeedModel) -> Result<f64, PhxError> {
+
+    phx_check_step_count(n_t)?;
+    phx_check_depths(ts_profile, &[ z0 ])?;
+
+    let (z_end, sign) = match direction {
+        VerticalDirection::Downward => (ts_profile.max_depth(), 1.0),
+        VerticalDirection::Upward => (0.0, -1.0),
+    };
+
+    let tof_max = phx_try_vertical_travel_time_ts_profile(z0, z_end, PHX_HARMONIC_MEAN_STEPS, ts_profile, g, eos, model)?;
+    if !(tof >= 0.0 && tof <= tof_max) {
+        return Err(PhxError::TimeOfFlightOutOfRange { value: tof, min: 0.0, max: tof_max });
+    }
+
+    // Runge-Kutta integration of dz/dt = c(z), the depth is kept within the profile
+    let (z_min, z_max) = (z0.min(z_end), z0.max(z_end));
+    let velocity = |z: f64| sign * phx_ts_profile_speed(ts_profile, z.clamp(z_min, z_max), g, eos, model);
+    let dt = tof / (n_t as f64);
+    let mut z = z0;
+
+    for _ in 0..n_t {
+        let k1 = velocity(z);
+        let k2 = velocity(z + 0.5 * dt * k1);
+        let k3 = velocity(z + 0.5 * dt * k2);
+        let k4 = velocity(z + dt * k3);
+        z += dt * (k1 + 2.0 * k2 + 2.0 * k3 + k4) / 6.0;
+    }
+
+    Ok(z.clamp(z_min, z_max))
+}
+
+// Calculates the depth by the time of flight, see phx_try_depth_by_travel_time_ts_profile
+// ts_profile - as an array of (f64, f64, f64) ordered by depth
+// Panics if the arguments are invalid
+pub fn phx_depth_by_travel_time_ts_profile(z0: f64, tof: f64, direction: VerticalDirection, n_t: i32, g: f64, ts_profile: &[(f64, f64, f64)]) -> f64 {
+
+    match phx_try_depth_by_travel_time_ts_profile(z0, tof, direction, n_t, &phx_ts_profile_from_tuples(ts_profile), g,
+                                                  EquationOfState::Eos80, SoundSpeedModel::ChenMillero) {
+        Ok(z) => z,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+/// Error of a range measured by travel time with a single sound speed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RangeErrorBudget {
+    /// effective sound speed along the direct path, m/s
+    pub c_eff: f64,
+    /// sound speed at the reference depth, m/s
+    pub c_ref: f64,
+    /// travel time along the direct path, s
+    pub t: f64,
+    /// true slant range, m
+    pub range: f64,
+    /// slant range obtained with the reference sound speed, m
+    pub range_ref: f64,
+}
+
+impl RangeErrorBudget {
+
+    /// Range error, m (positive - the range is overestimated)
+    pub fn error(&self) -> f64 {
+        self.range_ref - self.range
+    }
+
+    /// Relative range error
+    pub fn relative_error(&self) -> f64 {
+        self.c_ref / self.c_eff - 1.0
+    }
+}
+
+// Estimates the error of a slant range calculated by the travel time
+// with the sound speed at a single (reference) depth instead of the effective sound speed
+// z_src, z_rx - depths of the source and the receiver, m
+// r - horizontal range between the source and the receiver, m
+// z_ref - reference depth, m
+// ts_profile - vertical Temperature-Salinity profile
+// g - gravity acceleration, m/s^2
+// eos - equation of state to calculate water density
+// model - sound speed equation, SoundSpeedModel::ChenMillero for phx_speed_of_sound_unesco_calc
+#[allow(clippy::too_many_arguments)]
+pub fn phx_try_range_error_budget_ts_profile(z_src: f64, z_rx: f64, r: f64, z_ref: f64, ts_profile: &TsProfile,
+                                             g: f64, eos: EquationOfState, model: SoundSpeedModel) -> Result<RangeErrorBudget, PhxError> {
+
+    phx_check_depths(ts_profile, &[ z_ref ])?;
+
+    let c_eff = phx_try_effective_sound_speed_ts_profile(z_src, z_rx, r, ts_profile, g, eos, model)?;
+    let c_ref = phx_ts_profile_speed(ts_profile, z_ref, g, eos, model);
+    let range = r.hypot(z_rx - z_src);
+    let t = range / c_eff;
+
+    Ok(RangeErrorBudget { c_eff, c_ref, t, range, range_ref: t * c_ref })
+}
+
+// Estimates the range error, see phx_try_range_error_budget_ts_profile
+// ts_profile - as an array of (f64, f64, f64) ordered by depth
+// Panics if the arguments are invalid
+pub fn phx_range_error_budget_ts_profile(z_src: f64, z_rx: f64, r: f64, z_ref: f64, g: f64, ts_profile: &[(f64, f64, f64)]) -> RangeErrorBudget {
+
+    match phx_try_range_error_budget_ts_profile(z_src, z_rx, r, z_ref, &phx_ts_profile_from_tuples(ts_profile), g,
+                                                EquationOfState::Eos80, SoundSpeedModel::ChenMillero) {
+        Ok(budget) => budget,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{assert_approx_eq, phx_speed_of_sound_unesco_calc, phx_try_vertical_sound_path_ts_profile};
+    use crate::{PHX_ATM_PRESSURE_MBAR, PHX_GRAVITY_ACC_MPS2};
+
+    fn test_ts_profile() -> TsProfile {
+        TsProfile::from_tuples(&[ (0.0, 20.0, 35.0), (100.0, 12.0, 35.0), (1000.0, 4.0, 34.8) ]).unwrap()
+    }
+
+    #[test]
+    fn phx_try_harmonic_mean_sound_speed_ts_profile_test() {
+
+        let model = SoundSpeedModel::ChenMillero;
+        let (g, eos) = (PHX_GRAVITY_ACC_MPS2, EquationOfState::Eos80);
+
+        // a thin homogeneous layer at the surface
+        let c1 = phx_speed_of_sound_unesco_calc(20.0, PHX_ATM_PRESSURE_MBAR, 35.0);
+        let uniform = TsProfile::from_tuples(&[ (0.0, 20.0, 35.0), (1.0, 20.0, 35.0) ]).unwrap();
+        assert_approx_eq!(phx_try_harmonic_mean_sound_speed_ts_profile(0.0, 1.0, &uniform, g, eos, model, 10).unwrap(), c1, 2E-2);
+
+        let ts = test_ts_profile();
+        let c_top = phx_ts_profile_speed(&ts, 0.0, g, eos, model);
+        let c_bottom = phx_ts_profile_speed(&ts, 1000.0, g, eos, model);
+        let c_h = phx_try_harmonic_mean_sound_speed_ts_profile(0.0, 1000.0, &ts, g, eos, model, 1000).unwrap();
+
+        assert!(c_h < c_top && c_h > c_bottom);
+        assert_approx_eq!(phx_try_harmonic_mean_sound_speed_ts_profile(1000.0, 0.0, &ts, g, eos, model, 1000).unwrap(), c_h, 1E-9);
+        assert_approx_eq!(phx_try_harmonic_mean_sound_speed_ts_profile(0.0, 1000.0, &ts, g, eos, model, 100).unwrap(), c_h, 1E-3);
+        assert_approx_eq!(phx_try_harmonic_mean_sound_speed_ts_profile(500.0, 500.0, &ts, g, eos, model, 100).unwrap(),
+                          phx_ts_profile_speed(&ts, 500.0, g, eos, model), 1E-9);
+
+        assert_eq!(phx_try_harmonic_mean_sound_speed_ts_profile(0.0, 1500.0, &ts, g, eos, model, 100),
+                   Err(PhxError::DepthOutOfRange { value: 1500.0, min: 0.0, max: 1000.0 }));
+        assert_eq!(phx_try_harmonic_mean_sound_speed_ts_profile(0.0, 1000.0, &ts, g, eos, model, 0),
+                   Err(PhxError::InvalidStepCount { steps: 0 }));
+    }
+
+    #[test]
+    fn phx_try_range_error_budget_ts_profile_test() {
+
+        let model = SoundSpeedModel::ChenMillero;
+        let (g, eos) = (PHX_GRAVITY_ACC_MPS2, EquationOfState::Eos80);
+        let ts = test_ts_profile();
+
+        // vertical path: the harmonic mean
+        let c_h = phx_try_harmonic_mean_sound_speed_ts_profile(5.0, 800.0, &ts, g, eos, model, 1000).unwrap();
+        assert_approx_eq!(phx_try_effective_sound_speed_ts_profile(5.0, 800.0, 0.0, &ts, g, eos, model).unwrap(), c_h, 1E-9);
+
+        // an almost vertical slant path
+        let c_eff = phx_try_effective_sound_speed_ts_profile(5.0, 800.0, 20.0, &ts, g, eos, model).unwrap();
+        assert_approx_eq!(c_eff, c_h, 0.05);
+
+        // using the surface sound speed overestimates the range
+        let budget = phx_try_range_error_budget_ts_profile(5.0, 800.0, 500.0, 0.0, &ts, g, eos, model).unwrap();
+        assert!(budget.error() > 0.0);
+        assert_approx_eq!(budget.range, 500.0f64.hypot(795.0), 1E-9);
+        assert_approx_eq!(budget.error(), budget.range * budget.relative_error(), 1E-9);
+        assert_approx_eq!(budget.c_ref, phx_ts_profile_speed(&ts, 0.0, g, eos, model), 1E-12);
+
+        // no error when the reference sound speed is the effective one
+        let budget = phx_try_range_error_budget_ts_profile(500.0, 500.0, 1000.0, 500.0, &ts, g, eos, model).unwrap();
+        assert_approx_eq!(budget.error(), 0.0, 0.05);
+
+        // a thin thermocline is not smoothed out in a deep profile
+        let tuples = [ (0.0, 25.0, 35.0), (20.0, 25.0, 35.0), (30.0, 10.0, 35.0), (5000.0, 2.0, 34.7) ];
+        let deep = TsProfile::from_tuples(&tuples).unwrap();
+        let c_h = phx_try_harmonic_mean_sound_speed_ts_profile(5.0, 28.0, &deep, g, eos, model, 1000).unwrap();
+        assert_approx_eq!(phx_try_effective_sound_speed_ts_profile(5.0, 28.0, 1.0, &deep, g, eos, model).unwrap(), c_h, 0.05);
+        assert_approx_eq!(phx_try_harmonic_mean_sound_speed_ts_profile(5.0, 28.0, &deep, g, eos, model, 4).unwrap(), c_h, 0.05);
+
+        // the tuple versions
+        assert_eq!(phx_harmonic_mean_sound_speed_ts_profile(5.0, 28.0, g, &tuples, 1000), c_h);
+        assert_eq!(phx_effective_sound_speed_ts_profile(5.0, 45.0, 100.0, g, &tuples),
+                   phx_try_effective_sound_speed_ts_profile(5.0, 45.0, 100.0, &deep, g, eos, model).unwrap());
+        assert_eq!(phx_range_error_budget_ts_profile(5.0, 45.0, 100.0, 0.0, g, &tuples),
+                   phx_try_range_error_budget_ts_profile(5.0, 45.0, 100.0, 0.0, &deep, g, eos, model).unwrap());
+    }
+
+    #[test]
+    fn phx_vertical_travel_time_ts_profile_test() {
+
+        let model = SoundSpeedModel::ChenMillero;
+        let (g, eos) = (PHX_GRAVITY_ACC_MPS2, EquationOfState::Eos80);
+        let ts = test_ts_profile();
+
+        // upward and downward paths take the same time
+        let t_down = phx_try_vertical_travel_time_ts_profile(50.0, 700.0, 1000, &ts, g, eos, model).unwrap();
+        let t_up = phx_try_vertical_travel_time_ts_profile(700.0, 50.0, 1000, &ts, g, eos, model).unwrap();
+        assert_approx_eq!(t_down, t_up, 1E-12);
+        assert!(t_down > 650.0 / phx_ts_profile_speed(&ts, 0.0, g, eos, model));
+        assert!(t_down < 650.0 / phx_ts_profile_speed(&ts, 1000.0, g, eos, model));
+
+        // travel times add up
+        let t1 = phx_try_vertical_travel_time_ts_profile(50.0, 100.0, 1000, &ts, g, eos, model).unwrap();
+        let t2 = phx_try_vertical_travel_time_ts_profile(100.0, 700.0, 1000, &ts, g, eos, model).unwrap();
+        assert_approx_eq!(t1 + t2, t_down, 1E-7);
+        assert_approx_eq!(phx_try_vertical_travel_time_ts_profile(300.0, 300.0, 10, &ts, g, eos, model).unwrap(), 0.0, 1E-12);
+
+        // the inverse in both directions
+        let z = phx_try_depth_by_travel_time_ts_profile(50.0, t_down, VerticalDirection::Downward, 1000, &ts, g, eos, model).unwrap();
+        assert_approx_eq!(z, 700.0, 1E-3);
+        let z = phx_try_depth_by_travel_time_ts_profile(700.0, t_up, VerticalDirection::Upward, 1000, &ts, g, eos, model).unwrap();
+        assert_approx_eq!(z, 50.0, 1E-3);
+        assert_approx_eq!(phx_try_depth_by_travel_time_ts_profile(300.0, 0.0, VerticalDirection::Upward, 10, &ts, g, eos, model).unwrap(), 300.0, 1E-12);
+
+        // the surface is reached earlier than the time of flight
+        let t_surface = phx_try_vertical_travel_time_ts_profile(700.0, 0.0, 1000, &ts, g, eos, model).unwrap();
+        assert_eq!(phx_try_depth_by_travel_time_ts_profile(700.0, 1.0, VerticalDirection::Upward, 100, &ts, g, eos, model),
+                   Err(PhxError::TimeOfFlightOutOfRange { value: 1.0, min: 0.0, max: t_surface }));
+        assert_eq!(phx_try_depth_by_travel_time_ts_profile(1200.0, 0.1, VerticalDirection::Downward, 100, &ts, g, eos, model),
+                   Err(PhxError::DepthOutOfRange { value: 1200.0, min: 0.0, max: 1000.0 }));
+
+        // the tuple versions
+        let tuples = [ (0.0, 20.0, 35.0), (100.0, 12.0, 35.0), (1000.0, 4.0, 34.8) ];
+        assert_eq!(phx_vertical_travel_time_ts_profile(50.0, 700.0, 1000, g, &tuples), t_down);
+        assert_eq!(phx_depth_by_travel_time_ts_profile(50.0, t_down, VerticalDirection::Downward, 1000, g, &tuples),
+                   phx_try_depth_by_travel_time_ts_profile(50.0, t_down, VerticalDirection::Downward, 1000, &ts, g, eos, model).unwrap());
+
+        // from the surface down it is the vertical sound path
+        for &tof in [ 0.01, 0.1, 0.5 ].iter() {
+            assert_approx_eq!(phx_try_depth_by_travel_time_ts_profile(0.0, tof, VerticalDirection::Downward, 1000, &ts, g, eos, model).unwrap(),
+                              phx_try_vertical_sound_path_ts_profile(tof, 1000000, g, &ts, eos, model).unwrap(), 1E-3);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn phx_vertical_travel_time_ts_profile_panic_test() {
+        phx_vertical_travel_time_ts_profile(0.0, 1500.0, 100, PHX_GRAVITY_ACC_MPS2, &[ (0.0, 20.0, 35.0), (1000.0, 4.0, 34.8) ]);
+    }
+}
@@ -0,0 +1,189 @@
+// Algorithms for computation of fundamental properties of seawater.
+// Unesco technical papers in marine science vol. 44, 1983
+// https://darchive.mblwhoilibrary.org/bitstream/handle/1912/2470/059832eb.pdf
+
+use crate::phx_water_density_calc;
+
+// Depth of the standard ocean (S = 35 PSU, T = 0 °C) and its derivative by pressure
+// p - sea pressure, dbar
+// lat_rad - latitude, rad
+fn phx_unesco_depth(p: f64, lat_rad: f64) -> (f64, f64) {
+
+    let x = lat_rad.sin().powi(2);
+    let gr = 9.780318 * (1.0 + (5.2788E-3 + 2.36E-5 * x) * x) + 1.092E-6 * p;
+    let num = (((-1.82E-15 * p + 2.279E-10) * p - 2.2512E-5) * p + 9.72659) * p;
+    let num_p = ((-7.28E-15 * p + 6.837E-10) * p - 4.5024E-5) * p + 9.72659;
+
+    (num / gr, (num_p * gr - num * 1.092E-6) / (gr * gr))
+}
+
+// Calculates depth by pressure according to Saunders & Fofonoff (1976)
+// Unesco technical papers in marine science vol. 44, 1983, pp. 25-28
+// p - pressure, mBar
+// p0 - atmospheric pressure, mBar
+// lat_rad - latitude, rad
+// dyn_anomaly - geopotential anomaly, J/kg, (0 for the standard ocean)
+pub fn phx_depth_by_pressure_unesco_calc(p: f64, p0: f64, lat_rad: f64, dyn_anomaly: f64) -> f64 {
+    phx_unesco_depth((p - p0) / 100.0, lat_rad).0 + dyn_anomaly / 9.8
+}
+
+// Calculates pressure by depth, the exact inverse of phx_depth_by_pressure_unesco_calc
+// z - depth, m
+// p0 - atmospheric pressure, mBar
+// lat_rad - latitude, rad
+// dyn_anomaly - geopotential anomaly, J/kg, (0 for the standard ocean)
+// Returns pressure in mBar
+pub fn phx_pressure_by_depth_unesco_calc(z: f64, p0: f64, lat_rad: f64, dyn_anomaly: f64) -> f64 {
+
+    let z = z - dyn_anomaly / 9.8;
+
+    // Newton iterations starting from the hydrostatic estimate
+    let mut p = z / 0.99;
+    for _ in 0..20 {
+        let (z_est, dz_dp) = phx_unesco_depth(p, lat_rad);
+        let dp = (z_est - z) / dz_dp;
+        p -= dp;
+        if dp.abs() < 1E-10 {
+            break;
+        }
+    }
+
+    p * 100.0 + p0
+}
+
+// Calculates the adiabatic temperature gradient (adiabatic lapse rate) of seawater, °C/mBar
+// Bryden, H. L. (1973). New polynomials for thermal expansion, adiabatic temperature gradient
+// and potential temperature of sea water. Deep-Sea Research, 20, 401-408
+// Unesco technical papers in marine science vol. 44, 1983, pp. 36
+// t - temperature, °C
+// p - pressure, mBar
+// s - salinity, PSU
+pub fn phx_adiabatic_lapse_rate_calc(t: f64, p: f64, s: f64) -> f64 {
+
+    let p = p / 100.0;
+    let ds = s - 35.0;
+
+    let atg = (((-2.1687E-16 * t + 1.8676E-14) * t - 4.6206E-13) * p +
+               ((2.7759E-12 * t - 1.1351E-10) * ds + ((-5.4481E-14 * t + 8.733E-12) * t - 6.7795E-10) * t + 1.8741E-8)) * p +
+               (-4.2393E-8 * t + 1.8932E-6) * ds + ((6.6228E-10 * t - 6.836E-8) * t + 8.5258E-6) * t + 3.5803E-5;
+
+    atg / 100.0
+}
+
+// Calculates the potential temperature of seawater, °C
+// Fofonoff, N. P. (1977). Computation of potential temperature of seawater for an arbitrary
+// reference pressure. Deep-Sea Research, 24, 489-491 (4th order Runge-Kutta integration)
+// Unesco technical papers in marine science vol. 44, 1983, pp. 39
+// t - temperature, °C
+// p - pressure, mBar
+// s - salinity, PSU
+// pr - reference pressure, mBar
+pub fn phx_potential_temperature_calc(t: f64, p: f64, s: f64, pr: f64) -> f64 {
+
+    let h = pr - p;
+    let mut xk = h * phx_adiabatic_lapse_rate_calc(t, p, s);
+    let mut th = t + 0.5 * xk;
+    let mut q = xk;
+    let mut pp = p + 0.5 * h;
+
+    xk = h * phx_adiabatic_lapse_rate_calc(th, pp, s);
+    th += 0.29289322 * (xk - q);
+    q = 0.58578644 * xk + 0.121320344 * q;
+
+    xk = h * phx_adiabatic_lapse_rate_calc(th, pp, s);
+    th += 1.707106781 * (xk - q);
+    q = 3.414213562 * xk - 4.121320344 * q;
+
+    pp += 0.5 * h;
+    xk = h * phx_adiabatic_lapse_rate_calc(th, pp, s);
+
+    th + (xk - 2.0 * q) / 6.0
+}
+
+// Calculates the potential density of seawater, kg/m^3
+// i.e. density of a water parcel moved adiabatically to the reference pressure
+// t - temperature, °C
+// p - pressure, mBar
+// s - salinity, PSU
+// pr - reference pressure, mBar
+pub fn phx_potential_density_calc(t: f64, p: f64, s: f64, pr: f64) -> f64 {
+    phx_water_density_calc(phx_potential_temperature_calc(t, p, s, pr), pr, s)
+}
+
+// Calculates sigma-theta, potential density anomaly referenced to
+// zero sea pressure, kg/m^3
+// t - temperature, °C
+// p - sea pressure, mBar
+// s - salinity, PSU
+pub fn phx_sigma_theta_calc(t: f64, p: f64, s: f64) -> f64 {
+    phx_potential_density_calc(t, p, s, 0.0) - 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::assert_approx_eq;
+    use crate::tests::{REF_DPT_LAT, REF_LATS_DEG, REF_PRESSURE};
+
+    #[test]
+    fn phx_depth_by_pressure_unesco_calc_test() {
+
+        // Reference values according to
+        // Algorithms for computation of fundamental properties of seawater.
+        // Unesco technical papers in marine science vol. 44, 1983, pp. 28
+        // (the check value and the depth table REF_DPT_LAT)
+
+        assert_approx_eq!(phx_depth_by_pressure_unesco_calc(1E6, 0.0, 30.0f64.to_radians(), 0.0), 9712.653, 1E-3);
+
+        for l_idx in 0..REF_LATS_DEG.len() {
+
+            let lat_rad = REF_LATS_DEG[l_idx].to_radians();
+
+            for p_idx in 0..REF_PRESSURE.len() {
+
+                let z = phx_depth_by_pressure_unesco_calc(REF_PRESSURE[p_idx] + 1013.25, 1013.25, lat_rad, 0.0);
+                assert_approx_eq!(z, REF_DPT_LAT[l_idx][p_idx], 0.0051);
+
+                let p = phx_pressure_by_depth_unesco_calc(REF_DPT_LAT[l_idx][p_idx], 1013.25, lat_rad, 0.0);
+                assert_approx_eq!(p, REF_PRESSURE[p_idx] + 1013.25, 1.0);
+
+                let z_anomaly = phx_depth_by_pressure_unesco_calc(REF_PRESSURE[p_idx], 0.0, lat_rad, 9.8);
+                assert_approx_eq!(z_anomaly, z + 1.0, 1E-6);
+                assert_approx_eq!(phx_pressure_by_depth_unesco_calc(z_anomaly, 0.0, lat_rad, 9.8), REF_PRESSURE[p_idx], 1E-6);
+            }
+        }
+    }
+
+    #[test]
+    fn phx_potential_temperature_calc_test() {
+
+        // Reference values according to
+        // Algorithms for computation of fundamental properties of seawater.
+        // Unesco technical papers in marine science vol. 44, 1983, pp. 36, 39
+
+        assert_approx_eq!(phx_adiabatic_lapse_rate_calc(40.0, 1E6, 40.0), 3.255976E-6, 1E-12);
+        assert_approx_eq!(phx_potential_temperature_calc(40.0, 1E6, 40.0, 0.0), 36.89073, 1E-5);
+
+        // potential temperature at the reference pressure is the in situ temperature
+        assert_approx_eq!(phx_potential_temperature_calc(10.0, 2E5, 35.0, 2E5), 10.0, 1E-12);
+
+        // a water parcel brought back to its own pressure
+        let theta = phx_potential_temperature_calc(3.0, 5E5, 34.7, 0.0);
+        assert!(theta < 3.0);
+        assert_approx_eq!(phx_potential_temperature_calc(theta, 0.0, 34.7, 5E5), 3.0, 1E-5);
+    }
+
+    #[test]
+    fn phx_potential_density_calc_test() {
+
+        assert_approx_eq!(phx_potential_density_calc(10.0, 2E5, 35.0, 2E5), phx_water_density_calc(10.0, 2E5, 35.0), 1E-9);
+        assert_approx_eq!(phx_sigma_theta_calc(10.0, 0.0, 35.0), phx_water_density_calc(10.0, 0.0, 35.0) - 1000.0, 1E-9);
+
+        // deep water parcel: potential density at the surface is less than the in situ density
+        // and slightly greater than the density of water at the same in situ temperature
+        let sigma_theta = phx_sigma_theta_calc(3.0, 4E5, 34.7);
+        assert!(sigma_theta + 1000.0 < phx_water_density_calc(3.0, 4E5, 34.7));
+        assert!(sigma_theta + 1000.0 > phx_water_density_calc(3.0, 0.0, 34.7));
+    }
+}
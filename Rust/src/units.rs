@@ -0,0 +1,900 @@
+// Unit-safe physical quantities and typed versions of the water property functions
+//
+// The functions of this module have the same names as the plain f64 functions of the crate
+// (which are kept for FFI) and take the quantities instead of numbers in the units
+// the crate uses internally: mBar, °C, PSU, m, kHz and rad.
+// Pressure is the absolute pressure, as the crate feeds to EOS-80, the sound speed equations
+// and the hydrostatic integrators (PHX_ATM_PRESSURE_MBAR at the surface of the sea).
+// SeaPressure is the absolute pressure less PHX_ATM_PRESSURE_MBAR (zero at the surface), as the UNESCO 1983
+// algorithms of potential temperature, specific heat and freezing point, PSS-78 and TEOS-10 define it.
+// TEOS-10 functions take Absolute Salinity and Conservative Temperature, which have their own types.
+// Functions without quantities of these units have no typed versions: the sonar equations
+// (phx_*_signal_excess_calc, phx_*_required_source_level_calc) take levels in dB only,
+// phx_conductivity_ratio_calc takes conductivity, phx_gravity_depth_gradient_calc takes density,
+// phx_stability_summary takes the result of phx_buoyancy_frequency_ts_profile and phx_linterp is unitless.
+
+use crate::{EquationOfState, GravityModel, PhxError, SoundSpeedModel, TsProfile, SpreadingLaw, VerticalDirection};
+use crate::{DepthEstimate, DepthTolerance, PHX_ATM_PRESSURE_MBAR};
+use crate::absorption::{AbsorptionModel, BandAbsorption, PathAbsorption, SpectralWeighting};
+use crate::raytrace::{Eigenray, Ray, SoundSpeedProfile};
+use crate::stability::BuoyancyLayer;
+use crate::travel_time::RangeErrorBudget;
+
+/// Absolute pressure, stored in mBar
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Pressure(f64);
+
+impl Pressure {
+
+    pub fn from_mbar(p: f64) -> Pressure {
+        Pressure(p)
+    }
+
+    pub fn from_dbar(p: f64) -> Pressure {
+        Pressure(p * 100.0)
+    }
+
+    pub fn from_bar(p: f64) -> Pressure {
+        Pressure(p * 1000.0)
+    }
+
+    pub fn from_pa(p: f64) -> Pressure {
+        Pressure(p / 100.0)
+    }
+
+    pub fn from_kpa(p: f64) -> Pressure {
+        Pressure(p * 10.0)
+    }
+
+    pub fn mbar(&self) -> f64 {
+        self.0
+    }
+
+    pub fn dbar(&self) -> f64 {
+        self.0 / 100.0
+    }
+
+    pub fn bar(&self) -> f64 {
+        self.0 / 1000.0
+    }
+
+    pub fn pa(&self) -> f64 {
+        self.0 * 100.0
+    }
+
+    pub fn kpa(&self) -> f64 {
+        self.0 / 10.0
+    }
+
+    pub fn from_sea(p: SeaPressure) -> Pressure {
+        Pressure(p.0 + PHX_ATM_PRESSURE_MBAR)
+    }
+
+    pub fn sea(&self) -> SeaPressure {
+        SeaPressure(self.0 - PHX_ATM_PRESSURE_MBAR)
+    }
+}
+
+/// Sea pressure, the absolute pressure less the standard atmospheric pressure, stored in mBar
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct SeaPressure(f64);
+
+impl SeaPressure {
+
+    pub fn from_mbar(p: f64) -> SeaPressure {
+        SeaPressure(p)
+    }
+
+    pub fn from_dbar(p: f64) -> SeaPressure {
+        SeaPressure(p * 100.0)
+    }
+
+    pub fn from_absolute(p: Pressure) -> SeaPressure {
+        p.sea()
+    }
+
+    pub fn mbar(&self) -> f64 {
+        self.0
+    }
+
+    pub fn dbar(&self) -> f64 {
+        self.0 / 100.0
+    }
+
+    pub fn absolute(&self) -> Pressure {
+        Pressure::from_sea(*self)
+    }
+}
+
+/// Temperature, stored in °C
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Temperature(f64);
+
+impl Temperature {
+
+    pub fn from_celsius(t: f64) -> Temperature {
+        Temperature(t)
+    }
+
+    pub fn from_kelvin(t: f64) -> Temperature {
+        Temperature(t - 273.15)
+    }
+
+    pub fn from_fahrenheit(t: f64) -> Temperature {
+        Temperature((t - 32.0) / 1.8)
+    }
+
+    pub fn celsius(&self) -> f64 {
+        self.0
+    }
+
+    pub fn kelvin(&self) -> f64 {
+        self.0 + 273.15
+    }
+
+    pub fn fahrenheit(&self) -> f64 {
+        self.0 * 1.8 + 32.0
+    }
+}
+
+/// Practical salinity, stored in PSU
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Salinity(f64);
+
+impl Salinity {
+
+    pub fn from_psu(s: f64) -> Salinity {
+        Salinity(s)
+    }
+
+    pub fn psu(&self) -> f64 {
+        self.0
+    }
+}
+
+/// Depth (positive, 0 - water surface), stored in m
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Depth(f64);
+
+impl Depth {
+
+    pub fn from_m(z: f64) -> Depth {
+        Depth(z)
+    }
+
+    pub fn from_km(z: f64) -> Depth {
+        Depth(z * 1000.0)
+    }
+
+    pub fn m(&self) -> f64 {
+        self.0
+    }
+
+    pub fn km(&self) -> f64 {
+        self.0 / 1000.0
+    }
+}
+
+/// Frequency, stored in kHz
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Frequency(f64);
+
+impl Frequency {
+
+    pub fn from_hz(f: f64) -> Frequency {
+        Frequency(f / 1000.0)
+    }
+
+    pub fn from_khz(f: f64) -> Frequency {
+        Frequency(f)
+    }
+
+    pub fn hz(&self) -> f64 {
+        self.0 * 1000.0
+    }
+
+    pub fn khz(&self) -> f64 {
+        self.0
+    }
+}
+
+/// Angle (latitude, grazing angle etc.), stored in rad
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Angle(f64);
+
+impl Angle {
+
+    pub fn from_rad(a: f64) -> Angle {
+        Angle(a)
+    }
+
+    pub fn from_deg(a: f64) -> Angle {
+        Angle(a.to_radians())
+    }
+
+    pub fn rad(&self) -> f64 {
+        self.0
+    }
+
+    pub fn deg(&self) -> f64 {
+        self.0.to_degrees()
+    }
+}
+
+/// Distance (horizontal range, path length etc.), stored in m
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Distance(f64);
+
+impl Distance {
+
+    pub fn from_m(r: f64) -> Distance {
+        Distance(r)
+    }
+
+    pub fn from_km(r: f64) -> Distance {
+        Distance(r * 1000.0)
+    }
+
+    pub fn m(&self) -> f64 {
+        self.0
+    }
+
+    pub fn km(&self) -> f64 {
+        self.0 / 1000.0
+    }
+}
+
+/// Absolute Salinity (TEOS-10), stored in g/kg
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct AbsoluteSalinity(f64);
+
+impl AbsoluteSalinity {
+
+    pub fn from_g_per_kg(sa: f64) -> AbsoluteSalinity {
+        AbsoluteSalinity(sa)
+    }
+
+    pub fn g_per_kg(&self) -> f64 {
+        self.0
+    }
+}
+
+/// Conservative Temperature (TEOS-10), stored in °C
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct ConservativeTemperature(f64);
+
+impl ConservativeTemperature {
+
+    pub fn from_celsius(ct: f64) -> ConservativeTemperature {
+        ConservativeTemperature(ct)
+    }
+
+    pub fn celsius(&self) -> f64 {
+        self.0
+    }
+}
+
+// TS-profile as an array of (f64, f64, f64), the form the tuple functions of the crate take
+fn phx_ts_tuples(ts_profile: &[(Depth, Temperature, Salinity)]) -> Vec<(f64, f64, f64)> {
+    ts_profile.iter().map(|&(z, t, s)| (z.m(), t.celsius(), s.psu())).collect()
+}
+
+// Equation of state and sound speed, see the functions of the crate root
+
+// In situ density of water, kg/m^3, see crate::phx_water_density_calc
+pub fn phx_water_density_calc(t: Temperature, p: Pressure, s: Salinity) -> f64 {
+    crate::phx_water_density_calc(t.celsius(), p.mbar(), s.psu())
+}
+
+// Secant bulk modulus of water, see crate::phx_secant_bulk_modulus_calc
+pub fn phx_secant_bulk_modulus_calc(t: Temperature, p: Pressure, s: Salinity) -> f64 {
+    crate::phx_secant_bulk_modulus_calc(t.celsius(), p.mbar(), s.psu())
+}
+
+// Speed of sound by the UNESCO equation, m/s, see crate::phx_speed_of_sound_unesco_calc
+pub fn phx_speed_of_sound_unesco_calc(t: Temperature, p: Pressure, s: Salinity) -> f64 {
+    crate::phx_speed_of_sound_unesco_calc(t.celsius(), p.mbar(), s.psu())
+}
+
+// Gravity at sea level, m/s^2, see crate::phx_gravity_constant_wgs84_calc
+pub fn phx_gravity_constant_wgs84_calc(lat: Angle) -> f64 {
+    crate::phx_gravity_constant_wgs84_calc(lat.rad())
+}
+
+// Depth by pressure with constant density, see crate::phx_depth_by_pressure_calc
+// rho - water density, kg/m^3
+// g - gravity acceleration, m/s^2
+pub fn phx_depth_by_pressure_calc(p: Pressure, p0: Pressure, rho: f64, g: f64) -> Depth {
+    Depth::from_m(crate::phx_depth_by_pressure_calc(p.mbar(), p0.mbar(), rho, g))
+}
+
+// Pressure by depth with constant density, see crate::phx_pressure_by_depth_calc
+// rho - water density, kg/m^3
+// g - gravity acceleration, m/s^2
+pub fn phx_pressure_by_depth_calc(h: Depth, p0: Pressure, rho: f64, g: f64) -> Pressure {
+    Pressure::from_mbar(crate::phx_pressure_by_depth_calc(h.m(), p0.mbar(), rho, g))
+}
+
+// Depth by the specified TS-profile, see crate::phx_try_depth_by_pressure_ts_profile
+// g - gravity acceleration, m/s^2
+pub fn phx_try_depth_by_pressure_ts_profile(pm: Pressure, p0: Pressure, g: f64, ts_profile: &TsProfile, n_p: i32, eos: EquationOfState) -> Result<Depth, PhxError> {
+    crate::phx_try_depth_by_pressure_ts_profile(pm.mbar(), p0.mbar(), g, ts_profile, n_p, eos).map(Depth::from_m)
+}
+
+// g - gravity model of the water column
+pub fn phx_try_depth_by_pressure_ts_profile_gravity_model(pm: Pressure, p0: Pressure, g: GravityModel, ts_profile: &TsProfile, n_p: i32, eos: EquationOfState) -> Result<Depth, PhxError> {
+    crate::phx_try_depth_by_pressure_ts_profile_gravity_model(pm.mbar(), p0.mbar(), g, ts_profile, n_p, eos).map(Depth::from_m)
+}
+
+// Depth by the specified TS-profile, see crate::phx_depth_by_pressure_ts_profile
+// g - gravity acceleration, m/s^2
+pub fn phx_depth_by_pressure_ts_profile(pm: Pressure, p0: Pressure, g: f64, ts_profile: &[(Depth, Temperature, Salinity)], n_p: i32) -> Depth {
+    Depth::from_m(crate::phx_depth_by_pressure_ts_profile(pm.mbar(), p0.mbar(), g, &phx_ts_tuples(ts_profile), n_p))
+}
+
+// Depth by the specified TS-profile with the required tolerance, see crate::phx_try_depth_by_pressure_adaptive_ts_profile
+// g - gravity acceleration, m/s^2
+pub fn phx_try_depth_by_pressure_adaptive_ts_profile(pm: Pressure, p0: Pressure, g: f64, ts_profile: &TsProfile,
+                                                     tolerance: DepthTolerance, eos: EquationOfState) -> Result<DepthEstimate, PhxError> {
+    crate::phx_try_depth_by_pressure_adaptive_ts_profile(pm.mbar(), p0.mbar(), g, ts_profile, tolerance, eos)
+}
+
+// g - gravity model of the water column
+pub fn phx_try_depth_by_pressure_adaptive_ts_profile_gravity_model(pm: Pressure, p0: Pressure, g: GravityModel, ts_profile: &TsProfile,
+                                                                   tolerance: DepthTolerance, eos: EquationOfState) -> Result<DepthEstimate, PhxError> {
+    crate::phx_try_depth_by_pressure_adaptive_ts_profile_gravity_model(pm.mbar(), p0.mbar(), g, ts_profile, tolerance, eos)
+}
+
+// Hydrostatic pressure by the specified TS-profile, see crate::phx_try_pressure_by_depth_ts_profile
+// g - gravity acceleration, m/s^2
+pub fn phx_try_pressure_by_depth_ts_profile(z: Depth, p0: Pressure, g: f64, ts_profile: &TsProfile, n_z: i32, eos: EquationOfState) -> Result<Pressure, PhxError> {
+    crate::phx_try_pressure_by_depth_ts_profile(z.m(), p0.mbar(), g, ts_profile, n_z, eos).map(Pressure::from_mbar)
+}
+
+// g - gravity model of the water column
+pub fn phx_try_pressure_by_depth_ts_profile_gravity_model(z: Depth, p0: Pressure, g: GravityModel, ts_profile: &TsProfile, n_z: i32, eos: EquationOfState) -> Result<Pressure, PhxError> {
+    crate::phx_try_pressure_by_depth_ts_profile_gravity_model(z.m(), p0.mbar(), g, ts_profile, n_z, eos).map(Pressure::from_mbar)
+}
+
+// Hydrostatic pressure by the specified TS-profile, see crate::phx_pressure_by_depth_ts_profile
+// g - gravity acceleration, m/s^2
+pub fn phx_pressure_by_depth_ts_profile(z: Depth, p0: Pressure, g: f64, ts_profile: &[(Depth, Temperature, Salinity)], n_z: i32) -> Pressure {
+    Pressure::from_mbar(crate::phx_pressure_by_depth_ts_profile(z.m(), p0.mbar(), g, &phx_ts_tuples(ts_profile), n_z))
+}
+
+// Vertical path of sound, see crate::phx_try_vertical_sound_path_ts_profile
+// tof - time of flight, sec
+// g - gravity acceleration, m/s^2
+pub fn phx_try_vertical_sound_path_ts_profile(tof: f64, n_t: i32, g: f64, ts_profile: &TsProfile, eos: EquationOfState, model: SoundSpeedModel) -> Result<Depth, PhxError> {
+    crate::phx_try_vertical_sound_path_ts_profile(tof, n_t, g, ts_profile, eos, model).map(Depth::from_m)
+}
+
+// tof - time of flight, sec
+// g - gravity model of the water column
+pub fn phx_try_vertical_sound_path_ts_profile_gravity_model(tof: f64, n_t: i32, g: GravityModel, ts_profile: &TsProfile, eos: EquationOfState, model: SoundSpeedModel) -> Result<Depth, PhxError> {
+    crate::phx_try_vertical_sound_path_ts_profile_gravity_model(tof, n_t, g, ts_profile, eos, model).map(Depth::from_m)
+}
+
+// Vertical path of sound, see crate::phx_vertical_sound_path_ts_profile
+// tof - time of flight, sec
+// g - gravity acceleration, m/s^2
+pub fn phx_vertical_sound_path_ts_profile(tof: f64, n_t: i32, g: f64, ts_profile: &[(Depth, Temperature, Salinity)]) -> Depth {
+    Depth::from_m(crate::phx_vertical_sound_path_ts_profile(tof, n_t, g, &phx_ts_tuples(ts_profile)))
+}
+
+// Vertical path of sound by the closed-form layer integration, see crate::phx_try_vertical_sound_path_exact_ts_profile
+// tof - time of flight, sec
+// g - gravity acceleration, m/s^2
+pub fn phx_try_vertical_sound_path_exact_ts_profile(tof: f64, g: f64, ts_profile: &TsProfile, eos: EquationOfState, model: SoundSpeedModel) -> Result<Depth, PhxError> {
+    crate::phx_try_vertical_sound_path_exact_ts_profile(tof, g, ts_profile, eos, model).map(Depth::from_m)
+}
+
+// tof - time of flight, sec
+// g - gravity model of the water column
+pub fn phx_try_vertical_sound_path_exact_ts_profile_gravity_model(tof: f64, g: GravityModel, ts_profile: &TsProfile, eos: EquationOfState, model: SoundSpeedModel) -> Result<Depth, PhxError> {
+    crate::phx_try_vertical_sound_path_exact_ts_profile_gravity_model(tof, g, ts_profile, eos, model).map(Depth::from_m)
+}
+
+// Vertical path of sound, see crate::phx_vertical_sound_path_exact_ts_profile
+// tof - time of flight, sec
+// g - gravity acceleration, m/s^2
+pub fn phx_vertical_sound_path_exact_ts_profile(tof: f64, g: f64, ts_profile: &[(Depth, Temperature, Salinity)]) -> Depth {
+    Depth::from_m(crate::phx_vertical_sound_path_exact_ts_profile(tof, g, &phx_ts_tuples(ts_profile)))
+}
+
+// Freezing temperature of seawater, see crate::phx_water_fpoint_calc
+pub fn phx_water_fpoint_calc(p: SeaPressure, s: Salinity) -> Temperature {
+    Temperature::from_celsius(crate::phx_water_fpoint_calc(p.mbar(), s.psu()))
+}
+
+// Speed of sound by the Del Grosso equation, m/s, see crate::sound_speed
+pub fn phx_speed_of_sound_del_grosso_calc(t: Temperature, p: Pressure, s: Salinity) -> f64 {
+    crate::sound_speed::phx_speed_of_sound_del_grosso_calc(t.celsius(), p.mbar(), s.psu())
+}
+
+// Speed of sound by the Mackenzie equation, m/s, see crate::sound_speed
+pub fn phx_speed_of_sound_mackenzie_calc(t: Temperature, z: Depth, s: Salinity) -> f64 {
+    crate::sound_speed::phx_speed_of_sound_mackenzie_calc(t.celsius(), z.m(), s.psu())
+}
+
+// Speed of sound by the Coppens equation, m/s, see crate::sound_speed
+pub fn phx_speed_of_sound_coppens_calc(t: Temperature, z: Depth, s: Salinity) -> f64 {
+    crate::sound_speed::phx_speed_of_sound_coppens_calc(t.celsius(), z.m(), s.psu())
+}
+
+// Speed of sound by the Medwin equation, m/s, see crate::sound_speed
+pub fn phx_speed_of_sound_medwin_calc(t: Temperature, z: Depth, s: Salinity) -> f64 {
+    crate::sound_speed::phx_speed_of_sound_medwin_calc(t.celsius(), z.m(), s.psu())
+}
+
+// Speed of sound by the Wilson equation, m/s, see crate::sound_speed
+pub fn phx_speed_of_sound_wilson_calc(t: Temperature, p: Pressure, s: Salinity) -> f64 {
+    crate::sound_speed::phx_speed_of_sound_wilson_calc(t.celsius(), p.mbar(), s.psu())
+}
+
+// UNESCO 1983 algorithms, see crate::unesco
+// Depth and pressure are related by the difference of absolute pressures,
+// the rest of the algorithms take sea pressure
+
+// Depth by pressure according to Saunders & Fofonoff (1976)
+// dyn_anomaly - geopotential anomaly, J/kg, (0 for the standard ocean)
+pub fn phx_depth_by_pressure_unesco_calc(p: Pressure, p0: Pressure, lat: Angle, dyn_anomaly: f64) -> Depth {
+    Depth::from_m(crate::unesco::phx_depth_by_pressure_unesco_calc(p.mbar(), p0.mbar(), lat.rad(), dyn_anomaly))
+}
+
+// Pressure by depth, the inverse of phx_depth_by_pressure_unesco_calc
+// dyn_anomaly - geopotential anomaly, J/kg, (0 for the standard ocean)
+pub fn phx_pressure_by_depth_unesco_calc(z: Depth, p0: Pressure, lat: Angle, dyn_anomaly: f64) -> Pressure {
+    Pressure::from_mbar(crate::unesco::phx_pressure_by_depth_unesco_calc(z.m(), p0.mbar(), lat.rad(), dyn_anomaly))
+}
+
+// Adiabatic lapse rate, °C/mBar
+pub fn phx_adiabatic_lapse_rate_calc(t: Temperature, p: SeaPressure, s: Salinity) -> f64 {
+    crate::unesco::phx_adiabatic_lapse_rate_calc(t.celsius(), p.mbar(), s.psu())
+}
+
+// Potential temperature referenced to the pressure pr
+pub fn phx_potential_temperature_calc(t: Temperature, p: SeaPressure, s: Salinity, pr: SeaPressure) -> Temperature {
+    Temperature::from_celsius(crate::unesco::phx_potential_temperature_calc(t.celsius(), p.mbar(), s.psu(), pr.mbar()))
+}
+
+// Potential density referenced to the pressure pr, kg/m^3
+pub fn phx_potential_density_calc(t: Temperature, p: SeaPressure, s: Salinity, pr: SeaPressure) -> f64 {
+    crate::unesco::phx_potential_density_calc(t.celsius(), p.mbar(), s.psu(), pr.mbar())
+}
+
+// Potential density anomaly sigma-theta, kg/m^3
+pub fn phx_sigma_theta_calc(t: Temperature, p: SeaPressure, s: Salinity) -> f64 {
+    crate::unesco::phx_sigma_theta_calc(t.celsius(), p.mbar(), s.psu())
+}
+
+// Practical Salinity Scale 1978, see crate::pss78, p is the sea pressure
+
+// Practical salinity by the conductivity ratio R = C(S, t, p) / C(35, 15, 0)
+pub fn phx_salinity_pss78_calc(r: f64, t: Temperature, p: SeaPressure) -> Salinity {
+    Salinity::from_psu(crate::pss78::phx_salinity_pss78_calc(r, t.celsius(), p.mbar()))
+}
+
+// Practical salinity by the measured conductivity, mS/cm
+pub fn phx_salinity_by_conductivity_calc(c: f64, t: Temperature, p: SeaPressure) -> Salinity {
+    Salinity::from_psu(crate::pss78::phx_salinity_by_conductivity_calc(c, t.celsius(), p.mbar()))
+}
+
+// Conductivity ratio R = C(S, t, p) / C(35, 15, 0), the inverse of phx_salinity_pss78_calc
+pub fn phx_conductivity_ratio_pss78_calc(s: Salinity, t: Temperature, p: SeaPressure) -> f64 {
+    crate::pss78::phx_conductivity_ratio_pss78_calc(s.psu(), t.celsius(), p.mbar())
+}
+
+// Conductivity of seawater, mS/cm
+pub fn phx_conductivity_by_salinity_calc(s: Salinity, t: Temperature, p: SeaPressure) -> f64 {
+    crate::pss78::phx_conductivity_by_salinity_calc(s.psu(), t.celsius(), p.mbar())
+}
+
+// In situ density of water (kg/m^3) by the measured conductivity, mS/cm,
+// the salinity is taken at the sea pressure and the density at the absolute one
+pub fn phx_water_density_by_conductivity_calc(t: Temperature, p: SeaPressure, c: f64) -> f64 {
+    phx_water_density_calc(t, p.absolute(), phx_salinity_by_conductivity_calc(c, t, p))
+}
+
+// TEOS-10, see crate::teos10, p is the sea pressure
+
+// Specific volume, m^3/kg
+pub fn phx_teos10_specvol_calc(sa: AbsoluteSalinity, ct: ConservativeTemperature, p: SeaPressure) -> f64 {
+    crate::teos10::phx_teos10_specvol_calc(sa.g_per_kg(), ct.celsius(), p.mbar())
+}
+
+// In situ density, kg/m^3
+pub fn phx_teos10_density_calc(sa: AbsoluteSalinity, ct: ConservativeTemperature, p: SeaPressure) -> f64 {
+    crate::teos10::phx_teos10_density_calc(sa.g_per_kg(), ct.celsius(), p.mbar())
+}
+
+// Speed of sound, m/s
+pub fn phx_teos10_sound_speed_calc(sa: AbsoluteSalinity, ct: ConservativeTemperature, p: SeaPressure) -> f64 {
+    crate::teos10::phx_teos10_sound_speed_calc(sa.g_per_kg(), ct.celsius(), p.mbar())
+}
+
+// Absolute Salinity from Practical Salinity
+// dsa - Absolute Salinity Anomaly, g/kg
+pub fn phx_teos10_sa_from_sp_calc(sp: Salinity, dsa: f64) -> AbsoluteSalinity {
+    AbsoluteSalinity::from_g_per_kg(crate::teos10::phx_teos10_sa_from_sp_calc(sp.psu(), dsa))
+}
+
+// Conservative Temperature from potential temperature referenced to the sea surface
+pub fn phx_teos10_ct_from_pt_calc(sa: AbsoluteSalinity, pt: Temperature) -> ConservativeTemperature {
+    ConservativeTemperature::from_celsius(crate::teos10::phx_teos10_ct_from_pt_calc(sa.g_per_kg(), pt.celsius()))
+}
+
+// Conservative Temperature from in situ temperature
+pub fn phx_teos10_ct_from_t_calc(sa: AbsoluteSalinity, t: Temperature, p: SeaPressure) -> ConservativeTemperature {
+    ConservativeTemperature::from_celsius(crate::teos10::phx_teos10_ct_from_t_calc(sa.g_per_kg(), t.celsius(), p.mbar()))
+}
+
+// In situ freezing temperature
+// saturation_fraction - saturation fraction of dissolved air, 0..1
+pub fn phx_teos10_t_freezing_calc(sa: AbsoluteSalinity, p: SeaPressure, saturation_fraction: f64) -> Temperature {
+    Temperature::from_celsius(crate::teos10::phx_teos10_t_freezing_calc(sa.g_per_kg(), p.mbar(), saturation_fraction))
+}
+
+// Thermodynamic and transport properties, see crate::thermo and crate::transport
+
+// Specific heat capacity, J/(kg*°C), UNESCO 1983, p is the sea pressure
+pub fn phx_specific_heat_calc(t: Temperature, p: SeaPressure, s: Salinity) -> f64 {
+    crate::thermo::phx_specific_heat_calc(t.celsius(), p.mbar(), s.psu())
+}
+
+// Thermal expansion coefficient, 1/°C
+pub fn phx_thermal_expansion_calc(t: Temperature, p: Pressure, s: Salinity) -> f64 {
+    crate::thermo::phx_thermal_expansion_calc(t.celsius(), p.mbar(), s.psu())
+}
+
+// Haline contraction coefficient, 1/PSU
+pub fn phx_haline_contraction_calc(t: Temperature, p: Pressure, s: Salinity) -> f64 {
+    crate::thermo::phx_haline_contraction_calc(t.celsius(), p.mbar(), s.psu())
+}
+
+// Isothermal compressibility, 1/mBar
+pub fn phx_isothermal_compressibility_calc(t: Temperature, p: Pressure, s: Salinity) -> f64 {
+    crate::thermo::phx_isothermal_compressibility_calc(t.celsius(), p.mbar(), s.psu())
+}
+
+// Dynamic viscosity, Pa*s
+pub fn phx_dynamic_viscosity_calc(t: Temperature, s: Salinity) -> f64 {
+    crate::transport::phx_dynamic_viscosity_calc(t.celsius(), s.psu())
+}
+
+// Dynamic viscosity at the given pressure, Pa*s
+pub fn phx_try_dynamic_viscosity_calc(t: Temperature, p: Pressure, s: Salinity) -> Result<f64, PhxError> {
+    crate::transport::phx_try_dynamic_viscosity_calc(t.celsius(), p.mbar(), s.psu())
+}
+
+// Kinematic viscosity, m^2/s
+pub fn phx_kinematic_viscosity_calc(t: Temperature, p: Pressure, s: Salinity) -> f64 {
+    crate::transport::phx_kinematic_viscosity_calc(t.celsius(), p.mbar(), s.psu())
+}
+
+pub fn phx_try_kinematic_viscosity_calc(t: Temperature, p: Pressure, s: Salinity) -> Result<f64, PhxError> {
+    crate::transport::phx_try_kinematic_viscosity_calc(t.celsius(), p.mbar(), s.psu())
+}
+
+// Thermal conductivity, W/(m*°C)
+pub fn phx_thermal_conductivity_calc(t: Temperature, s: Salinity) -> f64 {
+    crate::transport::phx_thermal_conductivity_calc(t.celsius(), s.psu())
+}
+
+// Thermal conductivity at the given pressure, W/(m*°C)
+pub fn phx_try_thermal_conductivity_calc(t: Temperature, p: Pressure, s: Salinity) -> Result<f64, PhxError> {
+    crate::transport::phx_try_thermal_conductivity_calc(t.celsius(), p.mbar(), s.psu())
+}
+
+// Absorption of sound, dB/km, see crate::absorption
+
+// Francois and Garrison (1982), see crate::alpha_e_francois_garrison_calc
+pub fn alpha_e_francois_garrison_calc(f: Frequency, t: Temperature, s: Salinity, h: Depth, ph: f64) -> f64 {
+    crate::alpha_e_francois_garrison_calc(f.khz(), t.celsius(), s.psu(), h.m(), ph)
+}
+
+// Ainslie and McColm (1998)
+pub fn alpha_e_ainslie_mccolm_calc(f: Frequency, t: Temperature, s: Salinity, h: Depth, ph: f64) -> f64 {
+    crate::absorption::alpha_e_ainslie_mccolm_calc(f.khz(), t.celsius(), s.psu(), h.m(), ph)
+}
+
+// Fisher and Simmons (1977)
+pub fn alpha_e_fisher_simmons_calc(f: Frequency, t: Temperature, s: Salinity, h: Depth, ph: f64) -> f64 {
+    crate::absorption::alpha_e_fisher_simmons_calc(f.khz(), t.celsius(), s.psu(), h.m(), ph)
+}
+
+// Thorp (1967)
+pub fn alpha_e_thorp_calc(f: Frequency, t: Temperature, s: Salinity, h: Depth, ph: f64) -> f64 {
+    crate::absorption::alpha_e_thorp_calc(f.khz(), t.celsius(), s.psu(), h.m(), ph)
+}
+
+// Absorption of sound along a path through the TS-profile, see crate::absorption::phx_try_path_absorption_ts_profile
+// angle - angle between the path and the vertical (0 - vertical path)
+#[allow(clippy::too_many_arguments)]
+pub fn phx_try_path_absorption_ts_profile(f: Frequency, ph: f64, z1: Depth, z2: Depth, angle: Angle, n_z: i32,
+                                          ts_profile: &TsProfile, model: AbsorptionModel) -> Result<PathAbsorption, PhxError> {
+    crate::absorption::phx_try_path_absorption_ts_profile(f.khz(), ph, z1.m(), z2.m(), angle.rad(), n_z, ts_profile, model)
+}
+
+// Absorption over a frequency band, see crate::absorption::phx_try_band_absorption_calc
+#[allow(clippy::too_many_arguments)]
+pub fn phx_try_band_absorption_calc(f_low: Frequency, f_high: Frequency, t: Temperature, s: Salinity, h: Depth, ph: f64,
+                                    weighting: &SpectralWeighting, model: AbsorptionModel) -> Result<BandAbsorption, PhxError> {
+    crate::absorption::phx_try_band_absorption_calc(f_low.khz(), f_high.khz(), t.celsius(), s.psu(), h.m(), ph, weighting, model)
+}
+
+// Ambient noise spectral density levels, dB re 1 µPa^2/Hz, see crate::noise
+
+pub fn phx_noise_turbulence_calc(f: Frequency) -> f64 {
+    crate::noise::phx_noise_turbulence_calc(f.khz())
+}
+
+// shipping - shipping activity factor, 0..1
+pub fn phx_noise_shipping_calc(f: Frequency, shipping: f64) -> f64 {
+    crate::noise::phx_noise_shipping_calc(f.khz(), shipping)
+}
+
+// w - wind speed, m/s
+pub fn phx_noise_wind_calc(f: Frequency, w: f64) -> f64 {
+    crate::noise::phx_noise_wind_calc(f.khz(), w)
+}
+
+// sea_state - sea state, 0..6
+pub fn phx_noise_knudsen_calc(f: Frequency, sea_state: f64) -> f64 {
+    crate::noise::phx_noise_knudsen_calc(f.khz(), sea_state)
+}
+
+pub fn phx_noise_thermal_calc(f: Frequency) -> f64 {
+    crate::noise::phx_noise_thermal_calc(f.khz())
+}
+
+// rain_rate - rain rate, mm/h
+pub fn phx_noise_rain_calc(f: Frequency, rain_rate: f64) -> f64 {
+    crate::noise::phx_noise_rain_calc(f.khz(), rain_rate)
+}
+
+// Gravity, see crate::gravity
+
+// Normal gravity at the latitude and the ellipsoidal height (m), m/s^2
+pub fn phx_gravity_somigliana_calc(lat: Angle, height: f64) -> f64 {
+    crate::gravity::phx_gravity_somigliana_calc(lat.rad(), height)
+}
+
+// Gravity at depth, m/s^2
+// g0 - gravity at the water surface, m/s^2
+// rho - density of the overlying water, kg/m^3
+pub fn phx_gravity_at_depth_calc(g0: f64, z: Depth, rho: f64) -> f64 {
+    crate::gravity::phx_gravity_at_depth_calc(g0, z.m(), rho)
+}
+
+// Vertical sound propagation, see crate::travel_time
+// g - gravity acceleration, m/s^2
+
+// Harmonic mean sound speed between two depths, m/s
+pub fn phx_try_harmonic_mean_sound_speed_ts_profile(z1: Depth, z2: Depth, ts_profile: &TsProfile, g: f64, eos: EquationOfState,
+                                                    model: SoundSpeedModel, n_z: i32) -> Result<f64, PhxError> {
+    crate::travel_time::phx_try_harmonic_mean_sound_speed_ts_profile(z1.m(), z2.m(), ts_profile, g, eos, model, n_z)
+}
+
+pub fn phx_harmonic_mean_sound_speed_ts_profile(z1: Depth, z2: Depth, g: f64, ts_profile: &[(Depth, Temperature, Salinity)], n_z: i32) -> f64 {
+    crate::travel_time::phx_harmonic_mean_sound_speed_ts_profile(z1.m(), z2.m(), g, &phx_ts_tuples(ts_profile), n_z)
+}
+
+// Effective sound speed between a source and a receiver, m/s
+// r - horizontal range between the source and the receiver
+pub fn phx_try_effective_sound_speed_ts_profile(z_src: Depth, z_rx: Depth, r: Distance, ts_profile: &TsProfile,
+                                                g: f64, eos: EquationOfState, model: SoundSpeedModel) -> Result<f64, PhxError> {
+    crate::travel_time::phx_try_effective_sound_speed_ts_profile(z_src.m(), z_rx.m(), r.m(), ts_profile, g, eos, model)
+}
+
+pub fn phx_effective_sound_speed_ts_profile(z_src: Depth, z_rx: Depth, r: Distance, g: f64, ts_profile: &[(Depth, Temperature, Salinity)]) -> f64 {
+    crate::travel_time::phx_effective_sound_speed_ts_profile(z_src.m(), z_rx.m(), r.m(), g, &phx_ts_tuples(ts_profile))
+}
+
+// Vertical time of flight between two depths, sec
+pub fn phx_try_vertical_travel_time_ts_profile(z1: Depth, z2: Depth, n_z: i32, ts_profile: &TsProfile,
+                                               g: f64, eos: EquationOfState, model: SoundSpeedModel) -> Result<f64, PhxError> {
+    crate::travel_time::phx_try_vertical_travel_time_ts_profile(z1.m(), z2.m(), n_z, ts_profile, g, eos, model)
+}
+
+pub fn phx_vertical_travel_time_ts_profile(z1: Depth, z2: Depth, n_z: i32, g: f64, ts_profile: &[(Depth, Temperature, Salinity)]) -> f64 {
+    crate::travel_time::phx_vertical_travel_time_ts_profile(z1.m(), z2.m(), n_z, g, &phx_ts_tuples(ts_profile))
+}
+
+// Depth reached by sound traveling vertically from z0 during the time of flight
+// tof - time of flight, sec
+#[allow(clippy::too_many_arguments)]
+pub fn phx_try_depth_by_travel_time_ts_profile(z0: Depth, tof: f64, direction: VerticalDirection, n_t: i32, ts_profile: &TsProfile,
+                                               g: f64, eos: EquationOfState, model: SoundSpeedModel) -> Result<Depth, PhxError> {
+    crate::travel_time::phx_try_depth_by_travel_time_ts_profile(z0.m(), tof, direction, n_t, ts_profile, g, eos, model).map(Depth::from_m)
+}
+
+// tof - time of flight, sec
+pub fn phx_depth_by_travel_time_ts_profile(z0: Depth, tof: f64, direction: VerticalDirection, n_t: i32, g: f64, ts_profile: &[(Depth, Temperature, Salinity)]) -> Depth {
+    Depth::from_m(crate::travel_time::phx_depth_by_travel_time_ts_profile(z0.m(), tof, direction, n_t, g, &phx_ts_tuples(ts_profile)))
+}
+
+// Error of a slant range calculated with the sound speed at the reference depth z_ref
+// r - horizontal range between the source and the receiver
+#[allow(clippy::too_many_arguments)]
+pub fn phx_try_range_error_budget_ts_profile(z_src: Depth, z_rx: Depth, r: Distance, z_ref: Depth, ts_profile: &TsProfile,
+                                             g: f64, eos: EquationOfState, model: SoundSpeedModel) -> Result<RangeErrorBudget, PhxError> {
+    crate::travel_time::phx_try_range_error_budget_ts_profile(z_src.m(), z_rx.m(), r.m(), z_ref.m(), ts_profile, g, eos, model)
+}
+
+pub fn phx_range_error_budget_ts_profile(z_src: Depth, z_rx: Depth, r: Distance, z_ref: Depth, g: f64, ts_profile: &[(Depth, Temperature, Salinity)]) -> RangeErrorBudget {
+    crate::travel_time::phx_range_error_budget_ts_profile(z_src.m(), z_rx.m(), r.m(), z_ref.m(), g, &phx_ts_tuples(ts_profile))
+}
+
+// Stability of the water column, see crate::stability
+
+// Squared buoyancy frequency for every layer of a TS-profile
+// p0 - atmospheric pressure
+pub fn phx_buoyancy_frequency_ts_profile(ts_profile: &TsProfile, p0: Pressure, lat: Angle) -> Vec<BuoyancyLayer> {
+    crate::stability::phx_buoyancy_frequency_ts_profile(ts_profile, p0.mbar(), lat.rad())
+}
+
+// Ray tracing, see crate::raytrace
+
+// theta0 - launch grazing angle (positive - downwards)
+// r_max - horizontal range to trace the ray to
+pub fn phx_trace_ray(ssp: &SoundSpeedProfile, z0: Depth, theta0: Angle, r_max: Distance) -> Result<Ray, PhxError> {
+    crate::raytrace::phx_trace_ray(ssp, z0.m(), theta0.rad(), r_max.m())
+}
+
+// r - horizontal range between the source and the receiver
+// max_bounces - maximal number of surface and bottom reflections
+pub fn phx_find_eigenrays(ssp: &SoundSpeedProfile, z_src: Depth, z_rx: Depth, r: Distance, max_bounces: usize) -> Result<Vec<Eigenray>, PhxError> {
+    crate::raytrace::phx_find_eigenrays(ssp, z_src.m(), z_rx.m(), r.m(), max_bounces)
+}
+
+// Sonar equations, see crate::sonar
+// alpha - absorption, dB/km
+
+// One-way transmission loss, dB
+pub fn phx_transmission_loss_calc(r: Distance, alpha: f64, spreading: SpreadingLaw) -> f64 {
+    crate::sonar::phx_transmission_loss_calc(r.m(), alpha, spreading)
+}
+
+// Range at which one-way transmission loss reaches tl (dB)
+pub fn phx_range_by_transmission_loss_calc(tl: f64, alpha: f64, spreading: SpreadingLaw) -> Option<Distance> {
+    crate::sonar::phx_range_by_transmission_loss_calc(tl, alpha, spreading).map(Distance::from_m)
+}
+
+// Maximal detection range of a passive sonar (or an acoustic link)
+pub fn phx_passive_max_range_calc(sl: f64, nl: f64, di: f64, dt: f64, alpha: f64, spreading: SpreadingLaw) -> Option<Distance> {
+    crate::sonar::phx_passive_max_range_calc(sl, nl, di, dt, alpha, spreading).map(Distance::from_m)
+}
+
+// Maximal detection range of a monostatic active sonar
+pub fn phx_active_max_range_calc(sl: f64, ts: f64, nl: f64, di: f64, dt: f64, alpha: f64, spreading: SpreadingLaw) -> Option<Distance> {
+    crate::sonar::phx_active_max_range_calc(sl, ts, nl, di, dt, alpha, spreading).map(Distance::from_m)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    #[test]
+    fn units_conversion_test() {
+
+        assert_approx_eq!(Pressure::from_dbar(100.0).mbar(), 10000.0, 1E-9);
+        assert_approx_eq!(Pressure::from_pa(101325.0).mbar(), 1013.25, 1E-9);
+        assert_approx_eq!(Pressure::from_bar(1.0).kpa(), 100.0, 1E-9);
+        assert_approx_eq!(Pressure::from_kpa(101.325).pa(), 101325.0, 1E-6);
+        assert_approx_eq!(Pressure::from_mbar(2500.0).dbar(), 25.0, 1E-12);
+
+        assert_approx_eq!(Temperature::from_kelvin(283.15).celsius(), 10.0, 1E-9);
+        assert_approx_eq!(Temperature::from_fahrenheit(212.0).celsius(), 100.0, 1E-9);
+        assert_approx_eq!(Temperature::from_celsius(-40.0).fahrenheit(), -40.0, 1E-9);
+
+        assert_approx_eq!(Depth::from_km(1.5).m(), 1500.0, 1E-9);
+        assert_approx_eq!(Frequency::from_hz(12000.0).khz(), 12.0, 1E-12);
+        assert_approx_eq!(Angle::from_deg(180.0).rad(), std::f64::consts::PI, 1E-12);
+        assert_approx_eq!(Salinity::from_psu(35.0).psu(), 35.0, 1E-12);
+
+        assert!(Pressure::from_dbar(10.0) > Pressure::from_mbar(999.0));
+
+        let p0 = Pressure::from_mbar(crate::PHX_ATM_PRESSURE_MBAR);
+        assert_approx_eq!(p0.sea().mbar(), 0.0, 1E-12);
+        assert_approx_eq!(SeaPressure::from_dbar(1000.0).absolute().mbar(), 100000.0 + crate::PHX_ATM_PRESSURE_MBAR, 1E-9);
+        assert_eq!(SeaPressure::from_absolute(Pressure::from_sea(SeaPressure::from_dbar(50.0))), SeaPressure::from_dbar(50.0));
+    }
+
+    #[test]
+    fn units_typed_functions_test() {
+
+        let (t, s) = (Temperature::from_celsius(10.0), Salinity::from_psu(35.0));
+
+        // dbar can no longer be passed as mBar by mistake
+        let p = Pressure::from_dbar(1000.0);
+        assert_approx_eq!(phx_water_density_calc(t, p, s), crate::phx_water_density_calc(10.0, 100000.0, 35.0), 1E-12);
+        assert_approx_eq!(phx_speed_of_sound_unesco_calc(t, p, s), crate::phx_speed_of_sound_unesco_calc(10.0, 100000.0, 35.0), 1E-12);
+
+        let lat = Angle::from_deg(45.0);
+        let p0 = Pressure::from_mbar(crate::PHX_ATM_PRESSURE_MBAR);
+        let z = phx_depth_by_pressure_unesco_calc(p, p0, lat, 0.0);
+        assert_approx_eq!(phx_pressure_by_depth_unesco_calc(z, p0, lat, 0.0).mbar(), p.mbar(), 1E-6);
+
+        let rho = phx_water_density_calc(t, p0, s);
+        let g = phx_gravity_constant_wgs84_calc(lat);
+        assert_approx_eq!(phx_depth_by_pressure_calc(phx_pressure_by_depth_calc(Depth::from_m(250.0), p0, rho, g), p0, rho, g).m(), 250.0, 1E-9);
+
+        // UNESCO algorithms and PSS-78 take the sea pressure
+        assert!(phx_water_fpoint_calc(p0.sea(), s).celsius() < 0.0);
+
+        let c = phx_conductivity_by_salinity_calc(s, t, p.sea());
+        assert_approx_eq!(c, crate::pss78::phx_conductivity_by_salinity_calc(35.0, 10.0, 100000.0 - crate::PHX_ATM_PRESSURE_MBAR), 1E-12);
+        assert_approx_eq!(phx_salinity_by_conductivity_calc(c, t, p.sea()).psu(), 35.0, 1E-6);
+        assert_approx_eq!(phx_water_density_by_conductivity_calc(t, p.sea(), c), phx_water_density_calc(t, p, s), 1E-6);
+
+        assert_approx_eq!(alpha_e_francois_garrison_calc(Frequency::from_hz(12000.0), t, s, Depth::from_m(100.0), 8.0),
+                          crate::alpha_e_francois_garrison_calc(12.0, 10.0, 35.0, 100.0, 8.0), 1E-12);
+        assert_approx_eq!(phx_noise_thermal_calc(Frequency::from_khz(100.0)), 25.0, 1E-9);
+    }
+
+    #[test]
+    fn units_typed_profile_functions_test() {
+
+        let tuples = [ (0.0, 20.0, 35.0), (50.0, 12.0, 35.2), (200.0, 6.0, 34.8) ];
+        let typed: Vec<(Depth, Temperature, Salinity)> = tuples.iter()
+            .map(|&(z, t, s)| (Depth::from_m(z), Temperature::from_celsius(t), Salinity::from_psu(s)))
+            .collect();
+        let ts_profile = TsProfile::from_tuples(&tuples).unwrap();
+        let (g, eos, model) = (9.81, EquationOfState::Eos80, SoundSpeedModel::ChenMillero);
+        let p0 = Pressure::from_mbar(crate::PHX_ATM_PRESSURE_MBAR);
+
+        let p = phx_try_pressure_by_depth_ts_profile(Depth::from_m(150.0), p0, g, &ts_profile, 1000, eos).unwrap();
+        assert_approx_eq!(p.mbar(), crate::phx_try_pressure_by_depth_ts_profile(150.0, p0.mbar(), g, &ts_profile, 1000, eos).unwrap(), 1E-12);
+        assert_approx_eq!(phx_depth_by_pressure_ts_profile(p, p0, g, &typed, 1000).m(),
+                          crate::phx_depth_by_pressure_ts_profile(p.mbar(), p0.mbar(), g, &tuples, 1000), 1E-12);
+        assert_approx_eq!(phx_vertical_sound_path_ts_profile(0.05, 1000, g, &typed).m(),
+                          crate::phx_vertical_sound_path_ts_profile(0.05, 1000, g, &tuples), 1E-12);
+        assert_approx_eq!(phx_vertical_sound_path_exact_ts_profile(0.05, g, &typed).m(),
+                          crate::phx_vertical_sound_path_exact_ts_profile(0.05, g, &tuples), 1E-12);
+
+        let gravity = GravityModel::Constant(g);
+        assert_eq!(phx_try_pressure_by_depth_ts_profile_gravity_model(Depth::from_m(150.0), p0, gravity, &ts_profile, 1000, eos), Ok(p));
+        assert_eq!(phx_try_depth_by_pressure_ts_profile_gravity_model(p, p0, gravity, &ts_profile, 1000, eos),
+                   phx_try_depth_by_pressure_ts_profile(p, p0, g, &ts_profile, 1000, eos));
+        assert_eq!(phx_try_vertical_sound_path_exact_ts_profile_gravity_model(0.05, gravity, &ts_profile, eos, model),
+                   phx_try_vertical_sound_path_exact_ts_profile(0.05, g, &ts_profile, eos, model));
+
+        let (z1, z2) = (Depth::from_m(10.0), Depth::from_m(180.0));
+        assert_approx_eq!(phx_try_vertical_travel_time_ts_profile(z1, z2, 1000, &ts_profile, g, eos, model).unwrap(),
+                          phx_vertical_travel_time_ts_profile(z1, z2, 1000, g, &typed), 1E-12);
+        assert_approx_eq!(phx_harmonic_mean_sound_speed_ts_profile(z1, z2, g, &typed, 1000),
+                          crate::travel_time::phx_harmonic_mean_sound_speed_ts_profile(10.0, 180.0, g, &tuples, 1000), 1E-12);
+
+        let r = Distance::from_km(1.0);
+        let budget = phx_range_error_budget_ts_profile(z1, z2, r, z1, g, &typed);
+        assert_approx_eq!(budget.c_eff, phx_effective_sound_speed_ts_profile(z1, z2, r, g, &typed), 1E-12);
+
+        let layers = phx_buoyancy_frequency_ts_profile(&ts_profile, p0, Angle::from_deg(45.0));
+        assert_eq!(layers, crate::stability::phx_buoyancy_frequency_ts_profile(&ts_profile, p0.mbar(), 45f64.to_radians()));
+
+        let ssp = SoundSpeedProfile::from_ts_profile(&ts_profile, g, eos, model);
+        let ray = phx_trace_ray(&ssp, z1, Angle::from_deg(10.0), r).unwrap();
+        assert_eq!(ray, crate::raytrace::phx_trace_ray(&ssp, 10.0, 10f64.to_radians(), 1000.0).unwrap());
+
+        let alpha = 1.0;
+        let tl = phx_transmission_loss_calc(r, alpha, SpreadingLaw::Spherical);
+        assert_approx_eq!(phx_range_by_transmission_loss_calc(tl, alpha, SpreadingLaw::Spherical).unwrap().km(), 1.0, 1E-6);
+    }
+
+    #[test]
+    fn units_typed_teos10_functions_test() {
+
+        let sa = phx_teos10_sa_from_sp_calc(Salinity::from_psu(35.0), 0.0);
+        assert_approx_eq!(sa.g_per_kg(), crate::teos10::PHX_TEOS10_SSO, 1E-12);
+
+        let p = SeaPressure::from_dbar(1000.0);
+        let ct = phx_teos10_ct_from_t_calc(sa, Temperature::from_celsius(10.0), p);
+        assert_approx_eq!(ct.celsius(), crate::teos10::phx_teos10_ct_from_t_calc(sa.g_per_kg(), 10.0, 100000.0), 1E-12);
+        assert_approx_eq!(phx_teos10_density_calc(sa, ct, p), crate::teos10::phx_teos10_density_calc(sa.g_per_kg(), ct.celsius(), 100000.0), 1E-12);
+        assert_approx_eq!(phx_teos10_sound_speed_calc(sa, ct, p), crate::teos10::phx_teos10_sound_speed_calc(sa.g_per_kg(), ct.celsius(), 100000.0), 1E-12);
+        assert!(phx_teos10_t_freezing_calc(sa, SeaPressure::from_mbar(0.0), 1.0).celsius() < 0.0);
+    }
+}